@@ -2,6 +2,11 @@ extern crate embed_resource;
 use std::env;
 
 fn main() {
+    // AssetCollection checks paths and expands globs under assets/ at compile time, so rebuild
+    // whenever anything in there is added, removed or renamed
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=assets");
+
     let target = env::var("TARGET").unwrap();
     if target.contains("windows") {
         // on windows we will set our game icon as icon for the executable
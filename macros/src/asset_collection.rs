@@ -1,10 +1,17 @@
+use std::{
+    cmp::Ordering,
+    path::{Path, PathBuf},
+};
+
 use proc_macro::TokenStream;
 use proc_macro2::Ident;
 use quote::quote;
 use syn::{
     parenthesized,
     parse::{Parse, ParseStream},
-    parse_macro_input, token, Data, DeriveInput, Error, LitStr, Result,
+    parse_macro_input,
+    punctuated::Punctuated,
+    token, Data, DeriveInput, Error, LitStr, Result, Token, Type,
 };
 
 pub fn derive(input: TokenStream) -> TokenStream {
@@ -22,55 +29,117 @@ pub fn derive(input: TokenStream) -> TokenStream {
                                 let tokens: TokenStream = attr.tokens.clone().into();
                                 attributes.push((
                                     path_ident,
-                                    Attribute::AssetAttribute(parse_macro_input!(
+                                    Attribute::Asset(parse_macro_input!(tokens as AssetAttribute)),
+                                ));
+                            }
+                            "spine_asset" => {
+                                let tokens: TokenStream = attr.tokens.clone().into();
+                                attributes.push((
+                                    path_ident,
+                                    Attribute::SpineAsset(parse_macro_input!(
+                                        tokens as SpineAssetAttribute
+                                    )),
+                                ));
+                            }
+                            "asset_list" => {
+                                let tokens: TokenStream = attr.tokens.clone().into();
+                                attributes.push((
+                                    path_ident,
+                                    Attribute::AssetList(parse_macro_input!(
+                                        tokens as AssetListAttribute
+                                    )),
+                                ));
+                            }
+                            "asset_glob" => {
+                                let tokens: TokenStream = attr.tokens.clone().into();
+                                attributes.push((
+                                    path_ident,
+                                    Attribute::AssetGlob(parse_macro_input!(
                                         tokens as AssetAttribute
                                     )),
                                 ));
                             }
-                            "spine_asset" => {
+                            "spine_asset_glob" => {
                                 let tokens: TokenStream = attr.tokens.clone().into();
                                 attributes.push((
                                     path_ident,
-                                    Attribute::SpineAssetAttribute(parse_macro_input!(
+                                    Attribute::SpineAssetGlob(parse_macro_input!(
                                         tokens as SpineAssetAttribute
                                     )),
                                 ));
                             }
-                            _ => {
-                                unreachable!()
+                            // doc comments are the only other attributes a field may carry
+                            "doc" => {}
+                            unknown => {
+                                return TokenStream::from(
+                                    Error::new(
+                                        path_ident.span(),
+                                        format!(
+                                            "Unknown attribute `{}`. Expected one of `asset`, `spine_asset`, `asset_list`, `asset_glob` or `spine_asset_glob`.",
+                                            unknown
+                                        ),
+                                    )
+                                    .to_compile_error(),
+                                );
                             }
                         }
                     }
                 }
                 let mut asset_collection_field =
-                    AssetCollectionField::new(field.ident.clone().unwrap());
+                    AssetCollectionField::new(field.ident.clone().unwrap(), is_hash_map(&field.ty));
                 for (attribute_ident, attribute) in attributes.iter() {
+                    if !matches!(asset_collection_field.path, AssetCollectionPath::None) {
+                        return TokenStream::from(
+                            Error::new(attribute_ident.span(), "Multiple path attributes found.")
+                                .to_compile_error(),
+                        );
+                    }
+                    if let Err(error) = attribute.validate() {
+                        return TokenStream::from(error.to_compile_error());
+                    }
                     match attribute {
-                        Attribute::AssetAttribute(asset_attribute) => {
-                            if !matches!(asset_collection_field.path, AssetCollectionPath::None) {
-                                return TokenStream::from(
-                                    Error::new(
-                                        attribute_ident.span(),
-                                        "Multiple path attributes found.",
-                                    )
-                                    .to_compile_error(),
-                                );
-                            }
+                        Attribute::Asset(asset_attribute) => {
                             asset_collection_field.path =
                                 AssetCollectionPath::Path(asset_attribute.literal.value());
                         }
-                        Attribute::SpineAssetAttribute(asset_attribute) => {
-                            if !matches!(asset_collection_field.path, AssetCollectionPath::None) {
+                        Attribute::SpineAsset(asset_attribute) => {
+                            asset_collection_field.path =
+                                AssetCollectionPath::Spine(asset_attribute.literal.value());
+                        }
+                        Attribute::AssetList(asset_attribute) => {
+                            if asset_collection_field.keyed {
                                 return TokenStream::from(
                                     Error::new(
                                         attribute_ident.span(),
-                                        "Multiple path attributes found.",
+                                        "asset_list can only be used on Vec fields.",
                                     )
                                     .to_compile_error(),
                                 );
                             }
-                            asset_collection_field.path =
-                                AssetCollectionPath::Spine(asset_attribute.literal.value());
+                            asset_collection_field.path = AssetCollectionPath::List(
+                                asset_attribute
+                                    .literals
+                                    .iter()
+                                    .map(|literal| literal.value())
+                                    .collect(),
+                            );
+                        }
+                        Attribute::AssetGlob(asset_attribute) => {
+                            match expand_glob(&asset_attribute.literal) {
+                                Ok(paths) => {
+                                    asset_collection_field.path = AssetCollectionPath::Glob(paths)
+                                }
+                                Err(error) => return TokenStream::from(error.to_compile_error()),
+                            }
+                        }
+                        Attribute::SpineAssetGlob(asset_attribute) => {
+                            match expand_glob(&asset_attribute.literal) {
+                                Ok(paths) => {
+                                    asset_collection_field.path =
+                                        AssetCollectionPath::SpineGlob(paths)
+                                }
+                                Err(error) => return TokenStream::from(error.to_compile_error()),
+                            }
                         }
                     }
                 }
@@ -98,6 +167,8 @@ pub fn derive(input: TokenStream) -> TokenStream {
     }
 
     let mut load_assets = vec![];
+    let mut handles = vec![];
+    let mut skeletons = vec![];
     for asset_collection_field in asset_collection_fields.iter() {
         match &asset_collection_field.path {
             AssetCollectionPath::Path(path) => {
@@ -105,6 +176,9 @@ pub fn derive(input: TokenStream) -> TokenStream {
                 load_assets.push(quote! {
                     self.#field_ident = asset_server.load(#path);
                 });
+                handles.push(quote! {
+                    handles.push(self.#field_ident.clone_weak_untyped());
+                });
             }
             AssetCollectionPath::Spine(spine_path) => {
                 let field_ident = &asset_collection_field.ident;
@@ -116,6 +190,84 @@ pub fn derive(input: TokenStream) -> TokenStream {
                         ));
                     }
                 });
+                // skeleton data is created in memory, so wait on the files it is built from
+                handles.push(quote! {
+                    handles.push(bevy::asset::HandleUntyped::weak(bevy::asset::HandleId::from(concat!(#spine_path, "/skeleton.json"))));
+                    handles.push(bevy::asset::HandleUntyped::weak(bevy::asset::HandleId::from(concat!(#spine_path, "/skeleton.atlas"))));
+                });
+                skeletons.push(quote! {
+                    skeletons.push(self.#field_ident.clone_weak());
+                });
+            }
+            AssetCollectionPath::List(paths) => {
+                let field_ident = &asset_collection_field.ident;
+                load_assets.push(quote! {
+                    self.#field_ident = vec![#(asset_server.load(#paths)),*];
+                });
+                handles.push(quote! {
+                    handles.extend(self.#field_ident.iter().map(|handle| handle.clone_weak_untyped()));
+                });
+            }
+            AssetCollectionPath::Glob(paths) => {
+                let field_ident = &asset_collection_field.ident;
+                let (keys, paths): (Vec<&String>, Vec<&String>) =
+                    paths.iter().map(|(key, path)| (key, path)).unzip();
+                if asset_collection_field.keyed {
+                    load_assets.push(quote! {
+                        self.#field_ident = [#((#keys.to_string(), asset_server.load(#paths))),*]
+                            .into_iter()
+                            .collect();
+                    });
+                    handles.push(quote! {
+                        handles.extend(self.#field_ident.values().map(|handle| handle.clone_weak_untyped()));
+                    });
+                } else {
+                    load_assets.push(quote! {
+                        self.#field_ident = vec![#(asset_server.load(#paths)),*];
+                    });
+                    handles.push(quote! {
+                        handles.extend(self.#field_ident.iter().map(|handle| handle.clone_weak_untyped()));
+                    });
+                }
+            }
+            AssetCollectionPath::SpineGlob(paths) => {
+                let field_ident = &asset_collection_field.ident;
+                let (keys, paths): (Vec<&String>, Vec<&String>) =
+                    paths.iter().map(|(key, path)| (key, path)).unzip();
+                let new_skeletons: Vec<_> = paths
+                    .iter()
+                    .map(|spine_path| {
+                        quote! {
+                            skeletons.add(SkeletonData::new_from_json(
+                                asset_server.load(concat!(#spine_path, "/skeleton.json")),
+                                asset_server.load(concat!(#spine_path, "/skeleton.atlas")),
+                            ))
+                        }
+                    })
+                    .collect();
+                if asset_collection_field.keyed {
+                    load_assets.push(quote! {
+                        self.#field_ident = [#((#keys.to_string(), #new_skeletons)),*]
+                            .into_iter()
+                            .collect();
+                    });
+                    skeletons.push(quote! {
+                        skeletons.extend(self.#field_ident.values().map(|handle| handle.clone_weak()));
+                    });
+                } else {
+                    load_assets.push(quote! {
+                        self.#field_ident = vec![#(#new_skeletons),*];
+                    });
+                    skeletons.push(quote! {
+                        skeletons.extend(self.#field_ident.iter().map(|handle| handle.clone_weak()));
+                    });
+                }
+                handles.push(quote! {
+                    #(
+                        handles.push(bevy::asset::HandleUntyped::weak(bevy::asset::HandleId::from(concat!(#paths, "/skeleton.json"))));
+                        handles.push(bevy::asset::HandleUntyped::weak(bevy::asset::HandleId::from(concat!(#paths, "/skeleton.atlas"))));
+                    )*
+                });
             }
             AssetCollectionPath::None => {}
         }
@@ -126,13 +278,59 @@ pub fn derive(input: TokenStream) -> TokenStream {
             fn load_assets(&mut self, skeletons: &mut Assets<SkeletonData>, asset_server: &AssetServer) {
                 #(#load_assets)*
             }
+
+            fn handles(&self) -> Vec<bevy::asset::HandleUntyped> {
+                #[allow(unused_mut)]
+                let mut handles = vec![];
+                #(#handles)*
+                handles
+            }
+
+            fn skeletons(&self) -> Vec<Handle<SkeletonData>> {
+                #[allow(unused_mut)]
+                let mut skeletons = vec![];
+                #(#skeletons)*
+                skeletons
+            }
         }
     })
 }
 
 enum Attribute {
-    AssetAttribute(AssetAttribute),
-    SpineAssetAttribute(SpineAssetAttribute),
+    Asset(AssetAttribute),
+    SpineAsset(SpineAssetAttribute),
+    AssetList(AssetListAttribute),
+    AssetGlob(AssetAttribute),
+    SpineAssetGlob(SpineAssetAttribute),
+}
+
+impl Attribute {
+    /// Checks that every file the attribute refers to exists under `assets/`.
+    fn validate(&self) -> Result<()> {
+        match self {
+            Attribute::Asset(asset_attribute) => {
+                check_asset(&asset_attribute.literal, &asset_attribute.literal.value())
+            }
+            Attribute::SpineAsset(asset_attribute) => {
+                check_spine_asset(&asset_attribute.literal, &asset_attribute.literal.value())
+            }
+            Attribute::AssetList(asset_attribute) => {
+                for literal in asset_attribute.literals.iter() {
+                    check_asset(literal, &literal.value())?;
+                }
+                Ok(())
+            }
+            Attribute::AssetGlob(asset_attribute) => {
+                expand_glob(&asset_attribute.literal).map(|_| ())
+            }
+            Attribute::SpineAssetGlob(asset_attribute) => {
+                for (_, path) in expand_glob(&asset_attribute.literal)? {
+                    check_spine_asset(&asset_attribute.literal, &path)?;
+                }
+                Ok(())
+            }
+        }
+    }
 }
 
 struct AssetAttribute {
@@ -165,15 +363,33 @@ impl Parse for SpineAssetAttribute {
     }
 }
 
+struct AssetListAttribute {
+    _paren_token: token::Paren,
+    literals: Punctuated<LitStr, Token![,]>,
+}
+
+impl Parse for AssetListAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        Ok(AssetListAttribute {
+            _paren_token: parenthesized!(content in input),
+            literals: content.parse_terminated(|input| input.parse())?,
+        })
+    }
+}
+
 struct AssetCollectionField {
     ident: Ident,
+    /// Whether the field is a `HashMap` keyed by the part of each path matched by a glob.
+    keyed: bool,
     path: AssetCollectionPath,
 }
 
 impl AssetCollectionField {
-    pub fn new(ident: Ident) -> Self {
+    pub fn new(ident: Ident, keyed: bool) -> Self {
         Self {
             ident,
+            keyed,
             path: AssetCollectionPath::None,
         }
     }
@@ -183,4 +399,138 @@ enum AssetCollectionPath {
     None,
     Path(String),
     Spine(String),
+    List(Vec<String>),
+    /// Keys and paths matched by a glob, in natural order.
+    Glob(Vec<(String, String)>),
+    SpineGlob(Vec<(String, String)>),
+}
+
+fn is_hash_map(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == "HashMap")
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+fn assets_dir() -> PathBuf {
+    Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default()).join("assets")
+}
+
+fn check_asset(literal: &LitStr, path: &str) -> Result<()> {
+    if assets_dir().join(path).is_file() {
+        Ok(())
+    } else {
+        Err(Error::new(
+            literal.span(),
+            format!("Asset `{}` does not exist.", path),
+        ))
+    }
+}
+
+fn check_spine_asset(literal: &LitStr, path: &str) -> Result<()> {
+    for file in ["skeleton.json", "skeleton.atlas"] {
+        if !assets_dir().join(path).join(file).is_file() {
+            return Err(Error::new(
+                literal.span(),
+                format!("Spine asset `{}` is missing `{}`.", path, file),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Lists the entries of the assets directory matching a pattern with a single `*` in its last
+/// component. Each match is keyed by the part the `*` stands for.
+fn expand_glob(pattern: &LitStr) -> Result<Vec<(String, String)>> {
+    let value = pattern.value();
+    let (directory, file_pattern) = match value.rsplit_once('/') {
+        Some((directory, file_pattern)) => (directory, file_pattern),
+        None => ("", value.as_str()),
+    };
+    let (prefix, suffix) = match file_pattern.split_once('*') {
+        Some((prefix, suffix)) if !directory.contains('*') && !suffix.contains('*') => {
+            (prefix, suffix)
+        }
+        _ => {
+            return Err(Error::new(
+                pattern.span(),
+                "Glob patterns must contain exactly one `*`, in their last path component.",
+            ))
+        }
+    };
+    let entries = assets_dir().join(directory).read_dir().map_err(|error| {
+        Error::new(
+            pattern.span(),
+            format!("Could not read asset directory `{}`: {}", directory, error),
+        )
+    })?;
+    let mut paths = vec![];
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.len() >= prefix.len() + suffix.len()
+            && name.starts_with(prefix)
+            && name.ends_with(suffix)
+        {
+            let key = name[prefix.len()..name.len() - suffix.len()].to_string();
+            let path = if directory.is_empty() {
+                name
+            } else {
+                format!("{}/{}", directory, name)
+            };
+            paths.push((key, path));
+        }
+    }
+    if paths.is_empty() {
+        return Err(Error::new(
+            pattern.span(),
+            format!("No assets match `{}`.", value),
+        ));
+    }
+    paths.sort_by(|(key, _), (other_key, _)| natural_cmp(key, other_key));
+    Ok(paths)
+}
+
+/// Orders strings so that runs of digits compare by value, putting `2` before `10`.
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a_chars = a.chars().peekable();
+    let mut b_chars = b.chars().peekable();
+    loop {
+        match (a_chars.peek(), b_chars.peek()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a_char), Some(b_char)) if a_char.is_ascii_digit() && b_char.is_ascii_digit() => {
+                let mut a_number = String::new();
+                while let Some(digit) = a_chars.next_if(|c| c.is_ascii_digit()) {
+                    a_number.push(digit);
+                }
+                let mut b_number = String::new();
+                while let Some(digit) = b_chars.next_if(|c| c.is_ascii_digit()) {
+                    b_number.push(digit);
+                }
+                let a_number = a_number.trim_start_matches('0');
+                let b_number = b_number.trim_start_matches('0');
+                let ordering = a_number
+                    .len()
+                    .cmp(&b_number.len())
+                    .then_with(|| a_number.cmp(b_number));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(a_char), Some(b_char)) => {
+                let ordering = a_char.cmp(b_char);
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+                a_chars.next();
+                b_chars.next();
+            }
+        }
+    }
 }
@@ -1,6 +1,9 @@
 use proc_macro::TokenStream;
 
-#[proc_macro_derive(AssetCollection, attributes(asset, spine_asset))]
+#[proc_macro_derive(
+    AssetCollection,
+    attributes(asset, spine_asset, asset_list, asset_glob, spine_asset_glob)
+)]
 pub fn derive_asset_collection(input: TokenStream) -> TokenStream {
     asset_collection::derive(input)
 }
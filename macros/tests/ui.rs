@@ -0,0 +1,6 @@
+// the test crates have no assets directory, so every path is reported as missing
+#[test]
+fn ui() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
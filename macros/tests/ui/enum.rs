@@ -0,0 +1,9 @@
+use tale_of_the_bagger_macros::AssetCollection;
+
+#[derive(AssetCollection)]
+#[allow(dead_code)]
+enum Assets {
+    Missing,
+}
+
+fn main() {}
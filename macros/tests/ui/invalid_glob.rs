@@ -0,0 +1,10 @@
+use tale_of_the_bagger_macros::AssetCollection;
+
+#[derive(AssetCollection)]
+#[allow(dead_code)]
+struct Assets {
+    #[asset_glob("audio/*/bag_*.ogg")]
+    sounds: Vec<u32>,
+}
+
+fn main() {}
@@ -5,5 +5,17 @@ pub enum AppState {
     #[default]
     Loading,
     Menu,
+    Help,
+    Settings,
     Game,
+    Paused,
+    GameOver,
+}
+
+impl AppState {
+    /// States that can be pushed on top of another one. They despawn their own entities on exit
+    /// instead of clearing the scene, so the state underneath survives.
+    pub fn is_overlay(&self) -> bool {
+        matches!(self, AppState::Settings | AppState::Paused)
+    }
 }
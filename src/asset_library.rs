@@ -2,7 +2,10 @@ use bevy::prelude::*;
 use bevy_kira_audio::AudioSource;
 use bevy_spine::prelude::*;
 
-use crate::common::AssetCollection;
+use crate::{
+    common::AssetCollection,
+    game::{BagCatalog, DifficultyCurve, ProductCatalog},
+};
 
 #[derive(Default, Resource)]
 pub struct AssetLibrary {
@@ -10,6 +13,7 @@ pub struct AssetLibrary {
     pub fonts: FontAssets,
     pub spines: SpineAssets,
     pub audio: AudioAssets,
+    pub data: DataAssets,
 }
 
 impl AssetLibrary {
@@ -22,6 +26,29 @@ impl AssetLibrary {
         self.fonts.load_assets(skeletons, asset_server);
         self.spines.load_assets(skeletons, asset_server);
         self.audio.load_assets(skeletons, asset_server);
+        self.data.load_assets(skeletons, asset_server);
+    }
+
+    pub fn handles(&self) -> Vec<HandleUntyped> {
+        [
+            self.textures.handles(),
+            self.fonts.handles(),
+            self.spines.handles(),
+            self.audio.handles(),
+            self.data.handles(),
+        ]
+        .concat()
+    }
+
+    pub fn skeletons(&self) -> Vec<Handle<SkeletonData>> {
+        [
+            self.textures.skeletons(),
+            self.fonts.skeletons(),
+            self.spines.skeletons(),
+            self.audio.skeletons(),
+            self.data.skeletons(),
+        ]
+        .concat()
     }
 }
 
@@ -60,79 +87,12 @@ pub struct FontAssets {
 pub struct SpineAssets {
     #[spine_asset("spines/desk")]
     pub desk: Handle<SkeletonData>,
-    #[spine_asset("spines/bag")]
-    pub bag: Handle<SkeletonData>,
     #[spine_asset("spines/customer")]
     pub customer: Handle<SkeletonData>,
     #[spine_asset("spines/health")]
     pub health: Handle<SkeletonData>,
     #[spine_asset("spines/menu")]
     pub menu: Handle<SkeletonData>,
-
-    #[spine_asset("spines/products/ak47")]
-    pub product_ak47: Handle<SkeletonData>,
-    #[spine_asset("spines/products/antifreeze")]
-    pub product_antifreeze: Handle<SkeletonData>,
-    #[spine_asset("spines/products/avocado")]
-    pub product_avocado: Handle<SkeletonData>,
-    #[spine_asset("spines/products/bacon")]
-    pub product_bacon: Handle<SkeletonData>,
-    #[spine_asset("spines/products/batteries")]
-    pub product_batteries: Handle<SkeletonData>,
-    #[spine_asset("spines/products/beans")]
-    pub product_beans: Handle<SkeletonData>,
-    #[spine_asset("spines/products/beer")]
-    pub product_beer: Handle<SkeletonData>,
-    #[spine_asset("spines/products/bleach")]
-    pub product_bleach: Handle<SkeletonData>,
-    #[spine_asset("spines/products/boiling-water")]
-    pub product_boiling_water: Handle<SkeletonData>,
-    #[spine_asset("spines/products/bowling-ball")]
-    pub product_bowling_ball: Handle<SkeletonData>,
-    #[spine_asset("spines/products/bread")]
-    pub product_bread: Handle<SkeletonData>,
-    #[spine_asset("spines/products/chicken")]
-    pub product_chicken: Handle<SkeletonData>,
-    #[spine_asset("spines/products/cinder")]
-    pub product_cinder: Handle<SkeletonData>,
-    #[spine_asset("spines/products/cocaine")]
-    pub product_cocaine: Handle<SkeletonData>,
-    #[spine_asset("spines/products/coffee")]
-    pub product_coffee: Handle<SkeletonData>,
-    #[spine_asset("spines/products/eggs")]
-    pub product_eggs: Handle<SkeletonData>,
-    #[spine_asset("spines/products/good-stuff")]
-    pub product_good_stuff: Handle<SkeletonData>,
-    #[spine_asset("spines/products/heart")]
-    pub product_heart: Handle<SkeletonData>,
-    #[spine_asset("spines/products/ice")]
-    pub product_ice: Handle<SkeletonData>,
-    #[spine_asset("spines/products/ice-cream")]
-    pub product_ice_cream: Handle<SkeletonData>,
-    #[spine_asset("spines/products/jerky")]
-    pub product_jerky: Handle<SkeletonData>,
-    #[spine_asset("spines/products/katana")]
-    pub product_katana: Handle<SkeletonData>,
-    #[spine_asset("spines/products/ketchup")]
-    pub product_ketchup: Handle<SkeletonData>,
-    #[spine_asset("spines/products/milk")]
-    pub product_milk: Handle<SkeletonData>,
-    #[spine_asset("spines/products/plate")]
-    pub product_plate: Handle<SkeletonData>,
-    #[spine_asset("spines/products/rocket-fuel")]
-    pub product_rocket_fuel: Handle<SkeletonData>,
-    #[spine_asset("spines/products/skull")]
-    pub product_skull: Handle<SkeletonData>,
-    #[spine_asset("spines/products/soup")]
-    pub product_soup: Handle<SkeletonData>,
-    #[spine_asset("spines/products/taco")]
-    pub product_taco: Handle<SkeletonData>,
-    #[spine_asset("spines/products/torch")]
-    pub product_torch: Handle<SkeletonData>,
-    #[spine_asset("spines/products/watermelon")]
-    pub product_watermelon: Handle<SkeletonData>,
-    #[spine_asset("spines/products/xxx")]
-    pub product_xxx: Handle<SkeletonData>,
 }
 
 #[derive(Default, AssetCollection)]
@@ -150,53 +110,25 @@ pub struct AudioAssets {
     #[asset("audio/ding_dong.ogg")]
     pub ding_dong: Handle<AudioSource>,
 
-    #[asset("audio/announcement_1.ogg")]
-    pub announcement_1: Handle<AudioSource>,
-    #[asset("audio/announcement_2.ogg")]
-    pub announcement_2: Handle<AudioSource>,
-    #[asset("audio/announcement_3.ogg")]
-    pub announcement_3: Handle<AudioSource>,
-    #[asset("audio/announcement_4.ogg")]
-    pub announcement_4: Handle<AudioSource>,
-    #[asset("audio/announcement_5.ogg")]
-    pub announcement_5: Handle<AudioSource>,
-    #[asset("audio/announcement_6.ogg")]
-    pub announcement_6: Handle<AudioSource>,
-    #[asset("audio/announcement_7.ogg")]
-    pub announcement_7: Handle<AudioSource>,
-    #[asset("audio/announcement_8.ogg")]
-    pub announcement_8: Handle<AudioSource>,
-
-    #[asset("audio/bag_insert_1.ogg")]
-    pub bag_insert_1: Handle<AudioSource>,
-    #[asset("audio/bag_insert_2.ogg")]
-    pub bag_insert_2: Handle<AudioSource>,
-    #[asset("audio/bag_insert_3.ogg")]
-    pub bag_insert_3: Handle<AudioSource>,
-    #[asset("audio/bag_insert_4.ogg")]
-    pub bag_insert_4: Handle<AudioSource>,
-    #[asset("audio/bag_insert_5.ogg")]
-    pub bag_insert_5: Handle<AudioSource>,
-    #[asset("audio/bag_insert_6.ogg")]
-    pub bag_insert_6: Handle<AudioSource>,
-    #[asset("audio/bag_insert_7.ogg")]
-    pub bag_insert_7: Handle<AudioSource>,
-    #[asset("audio/bag_insert_8.ogg")]
-    pub bag_insert_8: Handle<AudioSource>,
-    #[asset("audio/bag_insert_9.ogg")]
-    pub bag_insert_9: Handle<AudioSource>,
-    #[asset("audio/bag_insert_10.ogg")]
-    pub bag_insert_10: Handle<AudioSource>,
-    #[asset("audio/bag_insert_11.ogg")]
-    pub bag_insert_11: Handle<AudioSource>,
-
-    #[asset("audio/bag_clear_success_1.ogg")]
-    pub bag_clear_success_1: Handle<AudioSource>,
-    #[asset("audio/bag_clear_success_2.ogg")]
-    pub bag_clear_success_2: Handle<AudioSource>,
-    #[asset("audio/bag_clear_success_3.ogg")]
-    pub bag_clear_success_3: Handle<AudioSource>,
+    #[asset_glob("audio/announcement_*.ogg")]
+    pub announcements: Vec<Handle<AudioSource>>,
+
+    #[asset_glob("audio/bag_insert_*.ogg")]
+    pub bag_inserts: Vec<Handle<AudioSource>>,
+
+    #[asset_glob("audio/bag_clear_success_*.ogg")]
+    pub bag_clear_successes: Vec<Handle<AudioSource>>,
 
     #[asset("audio/bag_clear_error.ogg")]
     pub bag_clear_error: Handle<AudioSource>,
 }
+
+#[derive(Default, AssetCollection)]
+pub struct DataAssets {
+    #[asset("data/shift.difficulty.ron")]
+    pub difficulty: Handle<DifficultyCurve>,
+    #[asset("data/products.catalog.ron")]
+    pub products: Handle<ProductCatalog>,
+    #[asset("data/standard.bags.ron")]
+    pub bags: Handle<BagCatalog>,
+}
@@ -5,4 +5,12 @@ pub use tale_of_the_bagger_macros::AssetCollection;
 
 pub trait AssetCollection {
     fn load_assets(&mut self, skeletons: &mut Assets<SkeletonData>, asset_server: &AssetServer);
+
+    /// Weak handles to every file loaded by [`AssetCollection::load_assets`], for tracking load
+    /// progress.
+    fn handles(&self) -> Vec<HandleUntyped>;
+
+    /// Weak handles to the skeletons created by [`AssetCollection::load_assets`], which are
+    /// only ready once their atlas pages have loaded too.
+    fn skeletons(&self) -> Vec<Handle<SkeletonData>>;
 }
@@ -7,7 +7,7 @@ pub struct ClearScenePlugin;
 
 impl Plugin for ClearScenePlugin {
     fn build(&self, app: &mut App) {
-        for state in AppState::iter() {
+        for state in AppState::iter().filter(|state| !state.is_overlay()) {
             app.add_system_set(SystemSet::on_exit(state).with_system(clear_scene));
         }
     }
@@ -4,9 +4,29 @@ pub enum CollisionShape {
     None,
     Point,
     Aabb { half_extents: Vec2 },
+    Circle { radius: f32 },
+    Polygon { vertices: Vec<Vec2> },
 }
 
 impl CollisionShape {
+    /// Builds a polygon from the convex hull of `vertices`, so that any outline (such as a Spine
+    /// bounding box attachment) can be used.
+    pub fn polygon(vertices: impl IntoIterator<Item = Vec2>) -> Self {
+        CollisionShape::Polygon {
+            vertices: convex_hull(vertices.into_iter().collect()),
+        }
+    }
+
+    /// Center of the shape relative to its translation.
+    pub fn center(&self) -> Vec2 {
+        match *self {
+            CollisionShape::Polygon { ref vertices } if !vertices.is_empty() => {
+                vertices.iter().copied().sum::<Vec2>() / vertices.len() as f32
+            }
+            _ => Vec2::ZERO,
+        }
+    }
+
     pub fn colliding(
         &self,
         translation: Vec2,
@@ -23,6 +43,12 @@ impl CollisionShape {
                     CollisionShape::Aabb {
                         half_extents: other_half_extents,
                     } => point.colliding_aabb(&Aabb::new(other_translation, other_half_extents)),
+                    CollisionShape::Circle {
+                        radius: other_radius,
+                    } => point.colliding_circle(&Circle::new(other_translation, other_radius)),
+                    CollisionShape::Polygon {
+                        vertices: ref other_vertices,
+                    } => point.colliding_polygon(&Polygon::new(other_translation, other_vertices)),
                 }
             }
             CollisionShape::Aabb { half_extents } => {
@@ -33,6 +59,48 @@ impl CollisionShape {
                     CollisionShape::Aabb {
                         half_extents: other_half_extents,
                     } => aabb.colliding_aabb(&Aabb::new(other_translation, other_half_extents)),
+                    CollisionShape::Circle {
+                        radius: other_radius,
+                    } => aabb.colliding_circle(&Circle::new(other_translation, other_radius)),
+                    CollisionShape::Polygon {
+                        vertices: ref other_vertices,
+                    } => aabb.colliding_polygon(&Polygon::new(other_translation, other_vertices)),
+                }
+            }
+            CollisionShape::Circle { radius } => {
+                let circle = Circle::new(translation, radius);
+                match *other {
+                    CollisionShape::None => false,
+                    CollisionShape::Point => circle.colliding_point(&Point::new(other_translation)),
+                    CollisionShape::Aabb {
+                        half_extents: other_half_extents,
+                    } => circle.colliding_aabb(&Aabb::new(other_translation, other_half_extents)),
+                    CollisionShape::Circle {
+                        radius: other_radius,
+                    } => circle.colliding_circle(&Circle::new(other_translation, other_radius)),
+                    CollisionShape::Polygon {
+                        vertices: ref other_vertices,
+                    } => circle.colliding_polygon(&Polygon::new(other_translation, other_vertices)),
+                }
+            }
+            CollisionShape::Polygon { ref vertices } => {
+                let polygon = Polygon::new(translation, vertices);
+                match *other {
+                    CollisionShape::None => false,
+                    CollisionShape::Point => {
+                        polygon.colliding_point(&Point::new(other_translation))
+                    }
+                    CollisionShape::Aabb {
+                        half_extents: other_half_extents,
+                    } => polygon.colliding_aabb(&Aabb::new(other_translation, other_half_extents)),
+                    CollisionShape::Circle {
+                        radius: other_radius,
+                    } => polygon.colliding_circle(&Circle::new(other_translation, other_radius)),
+                    CollisionShape::Polygon {
+                        vertices: ref other_vertices,
+                    } => {
+                        polygon.colliding_polygon(&Polygon::new(other_translation, other_vertices))
+                    }
                 }
             }
         }
@@ -57,6 +125,17 @@ impl Point {
         let difference = self.translation - aabb.translation;
         difference.x.abs() <= aabb.half_extents.x && difference.y.abs() <= aabb.half_extents.y
     }
+
+    pub fn colliding_circle(&self, circle: &Circle) -> bool {
+        self.translation.distance_squared(circle.translation) <= circle.radius * circle.radius
+    }
+
+    pub fn colliding_polygon(&self, polygon: &Polygon) -> bool {
+        !polygon.vertices.is_empty()
+            && polygon.edges().all(|(from, to)| {
+                (to - from).perp_dot(self.translation - (polygon.translation + from)) >= 0.
+            })
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
@@ -74,7 +153,7 @@ impl Aabb {
     }
 
     pub fn new_from_vertices(vertices: &[Vec2]) -> Option<Self> {
-        if vertices.len() > 0 {
+        if !vertices.is_empty() {
             let mut bottom_left = vertices[0];
             let mut top_right = vertices[0];
             for vertex in vertices.iter().skip(1) {
@@ -96,7 +175,238 @@ impl Aabb {
         point.colliding_aabb(self)
     }
 
-    pub fn colliding_aabb(&self, _other: &Aabb) -> bool {
-        todo!()
+    pub fn colliding_aabb(&self, other: &Aabb) -> bool {
+        let difference = self.translation - other.translation;
+        let reach = self.half_extents + other.half_extents;
+        difference.x.abs() <= reach.x && difference.y.abs() <= reach.y
+    }
+
+    pub fn colliding_circle(&self, circle: &Circle) -> bool {
+        let closest = circle.translation.clamp(
+            self.translation - self.half_extents,
+            self.translation + self.half_extents,
+        );
+        Point::new(closest).colliding_circle(circle)
+    }
+
+    pub fn colliding_polygon(&self, polygon: &Polygon) -> bool {
+        let vertices = [
+            Vec2::new(-self.half_extents.x, -self.half_extents.y),
+            Vec2::new(self.half_extents.x, -self.half_extents.y),
+            Vec2::new(self.half_extents.x, self.half_extents.y),
+            Vec2::new(-self.half_extents.x, self.half_extents.y),
+        ];
+        Polygon::new(self.translation, &vertices).colliding_polygon(polygon)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Circle {
+    pub translation: Vec2,
+    pub radius: f32,
+}
+
+impl Circle {
+    pub fn new(translation: Vec2, radius: f32) -> Self {
+        Self {
+            translation,
+            radius,
+        }
+    }
+
+    pub fn colliding_point(&self, point: &Point) -> bool {
+        point.colliding_circle(self)
+    }
+
+    pub fn colliding_aabb(&self, aabb: &Aabb) -> bool {
+        aabb.colliding_circle(self)
+    }
+
+    pub fn colliding_circle(&self, other: &Circle) -> bool {
+        let reach = self.radius + other.radius;
+        self.translation.distance_squared(other.translation) <= reach * reach
+    }
+
+    pub fn colliding_polygon(&self, polygon: &Polygon) -> bool {
+        let center = Point::new(self.translation);
+        if center.colliding_polygon(polygon) {
+            return true;
+        }
+        polygon.edges().any(|(from, to)| {
+            let from = polygon.translation + from;
+            let to = polygon.translation + to;
+            let edge = to - from;
+            let amount = if edge == Vec2::ZERO {
+                0.
+            } else {
+                ((self.translation - from).dot(edge) / edge.length_squared()).clamp(0., 1.)
+            };
+            Point::new(from + edge * amount).colliding_circle(self)
+        })
+    }
+}
+
+/// A convex polygon with counter-clockwise vertices relative to `translation`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Polygon<'a> {
+    pub translation: Vec2,
+    pub vertices: &'a [Vec2],
+}
+
+impl<'a> Polygon<'a> {
+    pub fn new(translation: Vec2, vertices: &'a [Vec2]) -> Self {
+        Self {
+            translation,
+            vertices,
+        }
+    }
+
+    pub fn colliding_point(&self, point: &Point) -> bool {
+        point.colliding_polygon(self)
+    }
+
+    pub fn colliding_aabb(&self, aabb: &Aabb) -> bool {
+        aabb.colliding_polygon(self)
+    }
+
+    pub fn colliding_circle(&self, circle: &Circle) -> bool {
+        circle.colliding_polygon(self)
+    }
+
+    pub fn colliding_polygon(&self, other: &Polygon) -> bool {
+        if self.vertices.is_empty() || other.vertices.is_empty() {
+            return false;
+        }
+        // separating axis theorem, the edge normals of both polygons are the only candidates
+        let offset = other.translation - self.translation;
+        !self
+            .edges()
+            .map(|(from, to)| (to - from).perp())
+            .chain(other.edges().map(|(from, to)| (to - from).perp()))
+            .any(|axis| {
+                let (min, max) = project(self.vertices, Vec2::ZERO, axis);
+                let (other_min, other_max) = project(other.vertices, offset, axis);
+                max < other_min || other_max < min
+            })
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (Vec2, Vec2)> + 'a {
+        let vertices = self.vertices;
+        vertices
+            .iter()
+            .zip(vertices.iter().cycle().skip(1))
+            .map(|(from, to)| (*from, *to))
+    }
+}
+
+fn project(vertices: &[Vec2], offset: Vec2, axis: Vec2) -> (f32, f32) {
+    vertices
+        .iter()
+        .map(|vertex| (*vertex + offset).dot(axis))
+        .fold((f32::MAX, f32::MIN), |(min, max), projection| {
+            (min.min(projection), max.max(projection))
+        })
+}
+
+// monotone chain, returns the hull counter-clockwise without repeating the first vertex
+fn convex_hull(mut vertices: Vec<Vec2>) -> Vec<Vec2> {
+    vertices.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    vertices.dedup();
+    if vertices.len() < 3 {
+        return vertices;
+    }
+    let mut lower = vec![];
+    for vertex in vertices.iter() {
+        convex_hull_push(&mut lower, *vertex);
+    }
+    let mut upper = vec![];
+    for vertex in vertices.iter().rev() {
+        convex_hull_push(&mut upper, *vertex);
+    }
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+    lower
+}
+
+fn convex_hull_push(hull: &mut Vec<Vec2>, vertex: Vec2) {
+    while hull.len() >= 2 {
+        let a = hull[hull.len() - 2];
+        let b = hull[hull.len() - 1];
+        if (b - a).perp_dot(vertex - a) <= 0. {
+            hull.pop();
+        } else {
+            break;
+        }
+    }
+    hull.push(vertex);
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use crate::common::CollisionShape;
+
+    fn square(half_extent: f32) -> CollisionShape {
+        CollisionShape::polygon([
+            Vec2::new(half_extent, half_extent),
+            Vec2::new(-half_extent, -half_extent),
+            Vec2::new(half_extent, -half_extent),
+            Vec2::new(-half_extent, half_extent),
+            Vec2::ZERO,
+        ])
+    }
+
+    #[test]
+    fn polygon_hull() {
+        if let CollisionShape::Polygon { vertices } = square(1.) {
+            assert_eq!(
+                vertices,
+                vec![
+                    Vec2::new(-1., -1.),
+                    Vec2::new(1., -1.),
+                    Vec2::new(1., 1.),
+                    Vec2::new(-1., 1.),
+                ]
+            );
+        } else {
+            unreachable!();
+        }
+    }
+
+    #[test]
+    fn colliding() {
+        let aabb = CollisionShape::Aabb {
+            half_extents: Vec2::new(2., 1.),
+        };
+        let circle = CollisionShape::Circle { radius: 1. };
+        let triangle =
+            CollisionShape::polygon([Vec2::new(-1., -1.), Vec2::new(1., -1.), Vec2::new(0., 1.)]);
+        let shapes = [
+            &CollisionShape::Point,
+            &aabb,
+            &circle,
+            &triangle,
+            &square(1.),
+        ];
+        for (i, shape) in shapes.iter().enumerate() {
+            for (j, other) in shapes.iter().enumerate() {
+                if i != 0 || j != 0 {
+                    assert!(shape.colliding(Vec2::ZERO, other, Vec2::new(0.1, 0.1)));
+                }
+                assert!(!shape.colliding(Vec2::ZERO, other, Vec2::new(10., 10.)));
+            }
+        }
+
+        assert!(aabb.colliding(Vec2::ZERO, &aabb, Vec2::new(4., 2.)));
+        assert!(!aabb.colliding(Vec2::ZERO, &aabb, Vec2::new(4.1, 0.)));
+        assert!(circle.colliding(Vec2::ZERO, &aabb, Vec2::new(2.9, 0.)));
+        assert!(!circle.colliding(Vec2::ZERO, &aabb, Vec2::new(2.9, 1.9)));
+        assert!(circle.colliding(Vec2::ZERO, &triangle, Vec2::new(-1.7, 0.5)));
+        assert!(!triangle.colliding(Vec2::ZERO, &CollisionShape::Point, Vec2::new(0.6, 0.6)));
+        assert!(!triangle.colliding(Vec2::ZERO, &square(0.4), Vec2::new(1.2, 1.)));
+        assert!(triangle.colliding(Vec2::ZERO, &square(1.), Vec2::new(1.2, 1.)));
+        assert!(!CollisionShape::None.colliding(Vec2::ZERO, &aabb, Vec2::ZERO));
     }
 }
@@ -0,0 +1,240 @@
+use std::{error::Error, fs, path::PathBuf};
+
+use bevy::{prelude::*, window::WindowMode};
+use bevy_kira_audio::{AudioApp, AudioChannel, AudioControl};
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+const CONFIG_FILE: &str = "settings.ron";
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub enum ConfigSystem {
+    Apply,
+}
+
+/// Loads [`Config`] from the platform config directory and applies it to the audio channels and
+/// window whenever it changes.
+pub struct ConfigPlugin;
+
+impl Plugin for ConfigPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_audio_channel::<SfxChannel>()
+            .add_audio_channel::<MusicChannel>()
+            .add_audio_channel::<AnnouncementChannel>()
+            .insert_resource(Config::load())
+            .add_system(config_apply.label(ConfigSystem::Apply));
+    }
+}
+
+/// Sound effects such as bagging and damage.
+#[derive(Resource)]
+pub struct SfxChannel;
+
+/// The store ambience and radio.
+#[derive(Resource)]
+pub struct MusicChannel;
+
+/// The ding dong and store announcements.
+#[derive(Resource)]
+pub struct AnnouncementChannel;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter)]
+pub enum VolumeControl {
+    Master,
+    Sfx,
+    Music,
+    Announcements,
+}
+
+impl VolumeControl {
+    pub fn name(&self) -> &'static str {
+        match self {
+            VolumeControl::Master => "Master",
+            VolumeControl::Sfx => "Sound Effects",
+            VolumeControl::Music => "Music & Radio",
+            VolumeControl::Announcements => "Announcements",
+        }
+    }
+}
+
+/// Player settings, persisted as `settings.ron` in the platform config directory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Resource)]
+#[serde(default)]
+pub struct Config {
+    pub master_volume: f32,
+    pub sfx_volume: f32,
+    pub music_volume: f32,
+    pub announcement_volume: f32,
+    pub muted: bool,
+    pub fullscreen: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            master_volume: 1.,
+            sfx_volume: 1.,
+            music_volume: 1.,
+            announcement_volume: 1.,
+            muted: false,
+            fullscreen: false,
+        }
+    }
+}
+
+impl Config {
+    /// Reads the saved config, or the defaults if there is none or it can't be read.
+    pub fn load() -> Self {
+        if let Some(path) = config_path() {
+            if let Ok(contents) = fs::read_to_string(&path) {
+                match Self::from_ron(&contents) {
+                    Ok(config) => return config,
+                    Err(error) => warn!("Ignoring invalid config {}: {}", path.display(), error),
+                }
+            }
+        }
+        Self::default()
+    }
+
+    /// Parses a config file, bringing volumes that were edited out of range back into it.
+    pub fn from_ron(contents: &str) -> Result<Self, Box<dyn Error>> {
+        let mut config: Self = ron::from_str(contents)?;
+        for control in VolumeControl::iter() {
+            config.set_volume(control, config.volume(control));
+        }
+        Ok(config)
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn Error>> {
+        if let Some(path) = config_path() {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(
+                path,
+                ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?,
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn volume(&self, control: VolumeControl) -> f32 {
+        match control {
+            VolumeControl::Master => self.master_volume,
+            VolumeControl::Sfx => self.sfx_volume,
+            VolumeControl::Music => self.music_volume,
+            VolumeControl::Announcements => self.announcement_volume,
+        }
+    }
+
+    /// Sets a volume between 0 and 1. Anything that isn't a number is taken as full volume.
+    pub fn set_volume(&mut self, control: VolumeControl, volume: f32) {
+        let volume = if volume.is_nan() {
+            1.
+        } else {
+            volume.clamp(0., 1.)
+        };
+        match control {
+            VolumeControl::Master => self.master_volume = volume,
+            VolumeControl::Sfx => self.sfx_volume = volume,
+            VolumeControl::Music => self.music_volume = volume,
+            VolumeControl::Announcements => self.announcement_volume = volume,
+        }
+    }
+
+    /// Volume a channel plays at once master volume and muting are taken into account.
+    pub fn effective_volume(&self, control: VolumeControl) -> f32 {
+        if self.muted {
+            0.
+        } else {
+            self.master_volume * self.volume(control)
+        }
+    }
+}
+
+/// Directory for files that should persist between runs, or `None` where there is no
+/// filesystem to write to.
+pub fn config_dir() -> Option<PathBuf> {
+    if cfg!(target_arch = "wasm32") {
+        return None;
+    }
+    let base = if cfg!(target_os = "windows") {
+        std::env::var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        std::env::var_os("HOME").map(|home| {
+            PathBuf::from(home)
+                .join("Library")
+                .join("Application Support")
+        })
+    } else {
+        std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+    };
+    base.map(|base| base.join("tale-of-the-bagger"))
+}
+
+fn config_path() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join(CONFIG_FILE))
+}
+
+fn config_apply(
+    mut windows: Option<ResMut<Windows>>,
+    config: Res<Config>,
+    sfx_channel: Res<AudioChannel<SfxChannel>>,
+    music_channel: Res<AudioChannel<MusicChannel>>,
+    announcement_channel: Res<AudioChannel<AnnouncementChannel>>,
+) {
+    if !config.is_changed() {
+        return;
+    }
+    sfx_channel.set_volume(config.effective_volume(VolumeControl::Sfx) as f64);
+    music_channel.set_volume(config.effective_volume(VolumeControl::Music) as f64);
+    announcement_channel.set_volume(config.effective_volume(VolumeControl::Announcements) as f64);
+    // ios always runs borderless fullscreen
+    if cfg!(not(target_os = "ios")) {
+        if let Some(window) = windows
+            .as_mut()
+            .and_then(|windows| windows.get_primary_mut())
+        {
+            window.set_mode(if config.fullscreen {
+                WindowMode::BorderlessFullscreen
+            } else {
+                WindowMode::Windowed
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::{Config, VolumeControl};
+
+    #[test]
+    fn effective_volume() {
+        let mut config = Config::default();
+        config.set_volume(VolumeControl::Master, 0.5);
+        config.set_volume(VolumeControl::Sfx, 2.);
+        assert_eq!(config.effective_volume(VolumeControl::Sfx), 0.5);
+        config.muted = true;
+        assert_eq!(config.effective_volume(VolumeControl::Sfx), 0.);
+    }
+
+    #[test]
+    fn out_of_range_volumes() {
+        let config =
+            Config::from_ron("(master_volume: 3., sfx_volume: -1., music_volume: NaN)").unwrap();
+        assert_eq!(config.master_volume, 1.);
+        assert_eq!(config.sfx_volume, 0.);
+        assert_eq!(config.music_volume, 1.);
+        assert_eq!(config.announcement_volume, 1.);
+    }
+
+    #[test]
+    fn missing_fields() {
+        let config: Config = ron::from_str("(muted: true)").unwrap();
+        assert!(config.muted);
+        assert_eq!(config.master_volume, 1.);
+    }
+}
@@ -1,27 +1,66 @@
 use bevy::{
-    asset::{AssetIo, AssetIoError, Metadata},
+    asset::{AssetIo, AssetIoError, FileType, Metadata},
     prelude::*,
-    utils::BoxedFuture,
+    utils::{BoxedFuture, HashSet},
 };
 use std::path::{Path, PathBuf};
 
-use include_dir::{include_dir, Dir};
+use include_dir::{include_dir, Dir, DirEntry};
 
 static ASSETS: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/assets");
 
-struct EmbeddedAssetIo(Box<dyn AssetIo>);
+/// Serves assets from the files embedded in the executable. With `disk_overrides`, files found
+/// by the wrapped platform IO take precedence, so mods can replace embedded assets.
+struct EmbeddedAssetIo {
+    io: Box<dyn AssetIo>,
+    disk_overrides: bool,
+}
+
+impl EmbeddedAssetIo {
+    fn embedded_dir(path: &Path) -> Option<&'static Dir<'static>> {
+        if path.as_os_str().is_empty() {
+            Some(&ASSETS)
+        } else {
+            ASSETS.get_dir(path)
+        }
+    }
+}
 
 impl AssetIo for EmbeddedAssetIo {
     fn load_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>> {
-        let file = ASSETS.get_entry(path).unwrap().as_file().unwrap();
-        Box::pin(async move { Ok(file.contents().to_vec()) })
+        if self.disk_overrides && self.io.is_file(path) {
+            return self.io.load_path(path);
+        }
+        let file = ASSETS.get_file(path);
+        Box::pin(async move {
+            match file {
+                Some(file) => Ok(file.contents().to_vec()),
+                None => Err(AssetIoError::NotFound(path.to_path_buf())),
+            }
+        })
     }
 
     fn read_directory(
         &self,
-        _path: &Path,
+        path: &Path,
     ) -> Result<Box<dyn Iterator<Item = PathBuf>>, AssetIoError> {
-        unimplemented!();
+        let mut entries: HashSet<PathBuf> = HashSet::default();
+        let embedded_dir = Self::embedded_dir(path);
+        if let Some(dir) = embedded_dir {
+            entries.extend(dir.entries().iter().map(|entry| entry.path().to_path_buf()));
+        }
+        if self.disk_overrides {
+            match self.io.read_directory(path) {
+                Ok(disk_entries) => entries.extend(disk_entries),
+                Err(error) if embedded_dir.is_none() => return Err(error),
+                Err(_) => {}
+            }
+        } else if embedded_dir.is_none() {
+            return Err(AssetIoError::NotFound(path.to_path_buf()));
+        }
+        let mut entries: Vec<PathBuf> = entries.into_iter().collect();
+        entries.sort();
+        Ok(Box::new(entries.into_iter()))
     }
 
     fn watch_path_for_changes(&self, _path: &Path) -> Result<(), AssetIoError> {
@@ -32,17 +71,119 @@ impl AssetIo for EmbeddedAssetIo {
         Ok(())
     }
 
-    fn get_metadata(&self, _path: &Path) -> Result<Metadata, AssetIoError> {
-        unimplemented!();
+    fn get_metadata(&self, path: &Path) -> Result<Metadata, AssetIoError> {
+        if self.disk_overrides {
+            if let Ok(metadata) = self.io.get_metadata(path) {
+                return Ok(metadata);
+            }
+        }
+        if path.as_os_str().is_empty() {
+            return Ok(Metadata::new(FileType::Directory));
+        }
+        match ASSETS.get_entry(path) {
+            Some(DirEntry::File(_)) => Ok(Metadata::new(FileType::File)),
+            Some(DirEntry::Dir(_)) => Ok(Metadata::new(FileType::Directory)),
+            None => Err(AssetIoError::NotFound(path.to_path_buf())),
+        }
     }
 }
 
-pub struct EmbeddedAssetIoPlugin;
+pub struct EmbeddedAssetIoPlugin {
+    /// Lets files on disk shadow embedded ones. Only useful where the platform IO reads from a
+    /// local filesystem.
+    pub disk_overrides: bool,
+}
 
 impl Plugin for EmbeddedAssetIoPlugin {
     fn build(&self, app: &mut App) {
         let default_io = AssetPlugin::default().create_platform_default_asset_io();
-        let asset_io = EmbeddedAssetIo(default_io);
+        let asset_io = EmbeddedAssetIo {
+            io: default_io,
+            disk_overrides: self.disk_overrides,
+        };
         app.insert_resource(AssetServer::new(asset_io));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use bevy::{
+        asset::{AssetIo, FileAssetIo},
+        utils::futures::now_or_never,
+    };
+
+    use super::{EmbeddedAssetIo, ASSETS};
+
+    fn embedded_asset_io(disk: &Path, disk_overrides: bool) -> EmbeddedAssetIo {
+        EmbeddedAssetIo {
+            io: Box::new(FileAssetIo::new(disk, false)),
+            disk_overrides,
+        }
+    }
+
+    fn load(asset_io: &EmbeddedAssetIo, path: &str) -> Vec<u8> {
+        now_or_never(asset_io.load_path(Path::new(path)))
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn lists_embedded_directories() {
+        let disk = std::env::temp_dir().join("bagger-embedded-assets-empty");
+        let asset_io = embedded_asset_io(&disk, false);
+        let entries: Vec<_> = asset_io
+            .read_directory(Path::new("data"))
+            .unwrap()
+            .collect();
+        assert!(entries.contains(&Path::new("data/standard.bags.ron").to_path_buf()));
+        assert!(asset_io.read_directory(Path::new("missing")).is_err());
+        assert!(asset_io.get_metadata(Path::new("data")).unwrap().is_dir());
+    }
+
+    #[test]
+    fn disk_overrides_take_precedence() {
+        let disk =
+            std::env::temp_dir().join(format!("bagger-embedded-assets-{}", std::process::id()));
+        fs::create_dir_all(disk.join("data")).unwrap();
+        fs::write(disk.join("data/standard.bags.ron"), "modded").unwrap();
+        fs::write(disk.join("data/extra.bags.ron"), "extra").unwrap();
+        let embedded = ASSETS
+            .get_file("data/standard.bags.ron")
+            .unwrap()
+            .contents();
+
+        let asset_io = embedded_asset_io(&disk, true);
+        assert_eq!(load(&asset_io, "data/standard.bags.ron"), b"modded");
+        assert_eq!(
+            load(&asset_io, "data/standard.rules.ron"),
+            ASSETS
+                .get_file("data/standard.rules.ron")
+                .unwrap()
+                .contents()
+        );
+        let entries: Vec<_> = asset_io
+            .read_directory(Path::new("data"))
+            .unwrap()
+            .collect();
+        assert!(entries.contains(&Path::new("data/extra.bags.ron").to_path_buf()));
+        assert!(entries.contains(&Path::new("data/standard.rules.ron").to_path_buf()));
+        assert_eq!(
+            entries
+                .iter()
+                .filter(|path| path.ends_with("standard.bags.ron"))
+                .count(),
+            1
+        );
+
+        let asset_io = embedded_asset_io(&disk, false);
+        assert_eq!(load(&asset_io, "data/standard.bags.ron"), embedded);
+        assert!(asset_io
+            .read_directory(Path::new("data"))
+            .unwrap()
+            .all(|path| !path.ends_with("extra.bags.ron")));
+
+        fs::remove_dir_all(&disk).unwrap();
+    }
+}
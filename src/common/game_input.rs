@@ -1,11 +1,23 @@
+use std::time::Duration;
+
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub enum GameInputSystem {
+    Update,
+}
 
 pub struct GameInputPlugin;
 
 impl Plugin for GameInputPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<GameInput>()
-            .add_system_to_stage(CoreStage::PreUpdate, game_input_update);
+            .init_resource::<GameInputVirtual>()
+            .add_system_to_stage(
+                CoreStage::PreUpdate,
+                game_input_update.label(GameInputSystem::Update),
+            );
     }
 }
 
@@ -28,20 +40,18 @@ impl GameInput {
     }
 
     pub fn drag_started(&self) -> Option<&GameInputDrag> {
-        for drag in self.drags.iter() {
-            if drag.started {
-                return Some(drag);
-            }
-        }
-        None
+        self.drags.iter().find(|drag| drag.started)
+    }
+
+    pub fn drag_from(&self, source: GameInputDragSource) -> Option<&GameInputDrag> {
+        self.drags.iter().find(|drag| drag.source == source)
     }
 
     pub fn drag_position(&self, id: u64) -> Option<Vec2> {
-        if let Some(drag) = self.drags.iter().find(|drag| drag.id == id) {
-            Some(drag.position)
-        } else {
-            None
-        }
+        self.drags
+            .iter()
+            .find(|drag| drag.id == id)
+            .map(|drag| drag.position)
     }
 
     pub fn drag_ended(&self, id: u64) -> bool {
@@ -51,9 +61,51 @@ impl GameInput {
             false
         }
     }
+
+    pub fn record(&self, delta: Duration) -> GameInputFrame {
+        GameInputFrame {
+            delta_nanos: delta.as_nanos() as u64,
+            cursor_position: self.cursor_position,
+            drags: self.drags.clone(),
+        }
+    }
+
+    pub fn replay(&mut self, frame: &GameInputFrame) {
+        self.cursor_position = frame.cursor_position;
+        self.drags = frame.drags.clone();
+        self.drag_id = self
+            .drags
+            .iter()
+            .map(|drag| drag.id.wrapping_add(1))
+            .max()
+            .unwrap_or(self.drag_id);
+    }
 }
 
-#[derive(Debug)]
+/// Drives the [`GameInputDragSource::Virtual`] drag for input without a pointer, such as a
+/// keyboard or gamepad. A drag is started at `position` while `pressed` is held, follows
+/// `position` and ends when `pressed` is released.
+#[derive(Default, Resource)]
+pub struct GameInputVirtual {
+    pub position: Option<Vec2>,
+    pub pressed: bool,
+}
+
+/// A snapshot of [`GameInput`] after a single frame, along with the time that frame took.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameInputFrame {
+    pub delta_nanos: u64,
+    pub cursor_position: Option<Vec2>,
+    pub drags: Vec<GameInputDrag>,
+}
+
+impl GameInputFrame {
+    pub fn delta(&self) -> Duration {
+        Duration::from_nanos(self.delta_nanos)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameInputDrag {
     id: u64,
     source: GameInputDragSource,
@@ -79,15 +131,38 @@ impl GameInputDrag {
         self.id
     }
 
+    pub fn source(&self) -> GameInputDragSource {
+        self.source
+    }
+
     pub fn position(&self) -> Vec2 {
         self.position
     }
+
+    /// The same drag on a later frame, held at `position`.
+    pub fn moved(&self, position: Vec2) -> Self {
+        Self {
+            position,
+            started: false,
+            ..self.clone()
+        }
+    }
+
+    /// The same drag on the frame it is let go.
+    pub fn released(&self) -> Self {
+        Self {
+            started: false,
+            ended: true,
+            ..self.clone()
+        }
+    }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GameInputDragSource {
     Cursor,
     Touch(u64),
+    Virtual,
 }
 
 fn game_input_update(
@@ -96,13 +171,14 @@ fn game_input_update(
     camera_query: Query<(&Camera, &GlobalTransform)>,
     mouse_buttons: Res<Input<MouseButton>>,
     touches: Res<Touches>,
+    game_input_virtual: Res<GameInputVirtual>,
 ) {
     let window_size = if let Some(window) = windows.get_primary() {
-        Vec2::new(window.width() as f32, window.height() as f32)
+        Vec2::new(window.width(), window.height())
     } else {
         Vec2::new(1440., 810.)
     };
-    let to_world_matrix = if let Some((camera, camera_transform)) = camera_query.get_single().ok() {
+    let to_world_matrix = if let Ok((camera, camera_transform)) = camera_query.get_single() {
         camera_transform.compute_matrix() * camera.projection_matrix().inverse()
     } else {
         Mat4::IDENTITY
@@ -202,4 +278,29 @@ fn game_input_update(
             mouse_drag.ended = true;
         }
     }
+
+    let virtual_drag = game_input
+        .drags
+        .iter()
+        .position(|drag| drag.source == GameInputDragSource::Virtual);
+    match (virtual_drag, game_input_virtual.position) {
+        (None, Some(position)) if game_input_virtual.pressed => {
+            let id = game_input.next_drag_id();
+            game_input.drags.push(GameInputDrag::new(
+                id,
+                GameInputDragSource::Virtual,
+                position,
+            ));
+        }
+        (Some(index), position) => {
+            let virtual_drag = &mut game_input.drags[index];
+            if let Some(position) = position {
+                virtual_drag.position = position;
+            }
+            if !game_input_virtual.pressed || position.is_none() {
+                virtual_drag.ended = true;
+            }
+        }
+        _ => {}
+    }
 }
@@ -0,0 +1,233 @@
+use std::{
+    collections::VecDeque,
+    error::Error,
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Write},
+    time::Duration,
+};
+
+use bevy::{prelude::*, time::TimeSystem, utils::Instant};
+
+use crate::AppState;
+
+use super::{launch_option, GameInput, GameInputFrame, GameInputSystem};
+
+const RECORD_ARG: &str = "--record-input";
+const RECORD_ENV_VAR: &str = "BAGGER_RECORD_INPUT";
+const REPLAY_ARG: &str = "--replay-input";
+const REPLAY_ENV_VAR: &str = "BAGGER_REPLAY_INPUT";
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub enum GameInputReplaySystem {
+    Start,
+    Time,
+    Replay,
+    Record,
+}
+
+/// Records [`GameInput`] to a file with `--record-input <path>`, or plays a recording back with
+/// `--replay-input <path>` instead of reading from the mouse and touch screen.
+///
+/// Recordings are one RON encoded [`GameInputFrame`] per line, starting with the first frame of
+/// the first shift so the menus can be navigated by hand. Pair them with `--seed` to replay that
+/// shift exactly.
+pub struct GameInputReplayPlugin;
+
+impl Plugin for GameInputReplayPlugin {
+    fn build(&self, app: &mut App) {
+        if let Some(path) = launch_option(REPLAY_ARG, REPLAY_ENV_VAR) {
+            match GameInputReplay::load(&path) {
+                Ok(replay) => {
+                    info!(
+                        "Replaying {} input frames from {}",
+                        replay.frames.len(),
+                        path
+                    );
+                    app.insert_resource(replay);
+                }
+                Err(error) => error!("Failed to load input replay {}: {}", path, error),
+            }
+        }
+        if let Some(path) = launch_option(RECORD_ARG, RECORD_ENV_VAR) {
+            match GameInputRecorder::create(&path) {
+                Ok(recorder) => {
+                    info!("Recording input to {}", path);
+                    app.insert_resource(recorder);
+                }
+                Err(error) => error!("Failed to create input recording {}: {}", path, error),
+            }
+        }
+        app.add_system_set(
+            SystemSet::on_enter(AppState::Game)
+                .with_system(game_input_replay_start.label(GameInputReplaySystem::Start)),
+        )
+        .add_system_to_stage(
+            CoreStage::First,
+            game_input_replay_time
+                .label(GameInputReplaySystem::Time)
+                .after(TimeSystem),
+        )
+        .add_system_to_stage(
+            CoreStage::PreUpdate,
+            game_input_replay
+                .label(GameInputReplaySystem::Replay)
+                .after(GameInputSystem::Update),
+        )
+        .add_system_to_stage(
+            CoreStage::PreUpdate,
+            game_input_record
+                .label(GameInputReplaySystem::Record)
+                .after(GameInputReplaySystem::Replay),
+        );
+    }
+}
+
+#[derive(Resource)]
+pub struct GameInputReplay {
+    /// Set once the first shift starts, which is where the recording begins.
+    active: bool,
+    frames: VecDeque<GameInputFrame>,
+    current: Option<GameInputFrame>,
+    time: Time,
+    startup: Instant,
+    elapsed: Duration,
+}
+
+impl GameInputReplay {
+    pub fn new(frames: impl IntoIterator<Item = GameInputFrame>) -> Self {
+        let startup = Instant::now();
+        Self {
+            active: false,
+            frames: frames.into_iter().collect(),
+            current: None,
+            time: Time::new(startup),
+            startup,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    pub fn load(path: &str) -> Result<Self, Box<dyn Error>> {
+        let mut frames = vec![];
+        for line in BufReader::new(File::open(path)?).lines() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                frames.push(ron::from_str(&line)?);
+            }
+        }
+        Ok(Self::new(frames))
+    }
+
+    pub fn finished(&self) -> bool {
+        self.frames.is_empty() && self.current.is_none()
+    }
+
+    /// Number of frames not played back yet.
+    pub fn remaining(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Moves on to the next frame, advancing the replayed clock by the time it took.
+    fn advance(&mut self) {
+        self.current = self.frames.pop_front();
+        if let Some(delta) = self.current.as_ref().map(|frame| frame.delta()) {
+            self.elapsed += delta;
+            let instant = self.startup + self.elapsed;
+            self.time.update_with_instant(instant);
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct GameInputRecorder {
+    /// Set once the first shift starts, which is where the recording begins.
+    active: bool,
+    writer: BufWriter<File>,
+}
+
+impl GameInputRecorder {
+    pub fn create(path: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self {
+            active: false,
+            writer: BufWriter::new(File::create(path)?),
+        })
+    }
+
+    fn write(&mut self, frame: &GameInputFrame) -> Result<(), Box<dyn Error>> {
+        writeln!(self.writer, "{}", ron::to_string(frame)?)?;
+        // the app may exit without dropping resources, so never leave frames in the buffer
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Starts the replay and the recording on the first frame of the first shift. That frame is
+/// already underway, so it is played back or written right away.
+fn game_input_replay_start(
+    replay: Option<ResMut<GameInputReplay>>,
+    recorder: Option<ResMut<GameInputRecorder>>,
+    mut game_input: ResMut<GameInput>,
+    mut time: ResMut<Time>,
+) {
+    if let Some(mut replay) = replay {
+        if !replay.active {
+            replay.active = true;
+            replay.advance();
+            if let Some(frame) = &replay.current {
+                *time = replay.time.clone();
+                game_input.replay(frame);
+            }
+        }
+    }
+    if let Some(mut recorder) = recorder {
+        if !recorder.active {
+            recorder.active = true;
+            if let Err(error) = recorder.write(&game_input.record(time.delta())) {
+                error!("Failed to record input: {}", error);
+            }
+        }
+    }
+}
+
+fn game_input_replay_time(replay: Option<ResMut<GameInputReplay>>, mut time: ResMut<Time>) {
+    if let Some(mut replay) = replay {
+        if replay.active {
+            replay.advance();
+            if replay.current.is_some() {
+                *time = replay.time.clone();
+            }
+        }
+    }
+}
+
+fn game_input_replay(
+    mut commands: Commands,
+    mut game_input: ResMut<GameInput>,
+    replay: Option<Res<GameInputReplay>>,
+) {
+    if let Some(replay) = replay {
+        if !replay.active {
+            return;
+        }
+        if let Some(frame) = &replay.current {
+            game_input.replay(frame);
+        } else if replay.finished() {
+            info!("Input replay finished");
+            commands.remove_resource::<GameInputReplay>();
+        }
+    }
+}
+
+fn game_input_record(
+    recorder: Option<ResMut<GameInputRecorder>>,
+    game_input: Res<GameInput>,
+    time: Res<Time>,
+) {
+    if let Some(mut recorder) = recorder {
+        if !recorder.active {
+            return;
+        }
+        if let Err(error) = recorder.write(&game_input.record(time.delta())) {
+            error!("Failed to record input: {}", error);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::AppState;
+
+use super::launch_option;
+
+const SEED_ENV_VAR: &str = "BAGGER_SEED";
+const SEED_ARG: &str = "--seed";
+
+// mixed into the seed so the cosmetic stream never mirrors the gameplay stream
+const COSMETIC_STREAM: u64 = 0x9e37_79b9_7f4a_7c15;
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub enum GameRngSystem {
+    Reseed,
+}
+
+pub struct GameRngPlugin;
+
+impl Plugin for GameRngPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(GameRng::new(fixed_seed()))
+            .add_system_set(
+                SystemSet::on_enter(AppState::Game)
+                    .with_system(game_rng_reseed.label(GameRngSystem::Reseed)),
+            );
+    }
+}
+
+/// All gameplay randomness is drawn from here so a shift can be replayed from its seed.
+///
+/// `gameplay` drives anything that affects the outcome of a shift, while `cosmetic` drives
+/// sounds and visuals so that they can change without desyncing a replay.
+#[derive(Resource)]
+pub struct GameRng {
+    seed: u64,
+    fixed_seed: Option<u64>,
+    gameplay: StdRng,
+    cosmetic: StdRng,
+}
+
+impl GameRng {
+    pub fn new(fixed_seed: Option<u64>) -> Self {
+        let seed = fixed_seed.unwrap_or_else(rand::random);
+        Self {
+            seed,
+            fixed_seed,
+            gameplay: StdRng::seed_from_u64(seed),
+            cosmetic: StdRng::seed_from_u64(seed ^ COSMETIC_STREAM),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Starts a new run, reusing the fixed seed if one was given.
+    pub fn reseed(&mut self) {
+        *self = Self::new(self.fixed_seed);
+    }
+
+    pub fn gameplay(&mut self) -> &mut StdRng {
+        &mut self.gameplay
+    }
+
+    pub fn cosmetic(&mut self) -> &mut StdRng {
+        &mut self.cosmetic
+    }
+}
+
+fn fixed_seed() -> Option<u64> {
+    let seed = launch_option(SEED_ARG, SEED_ENV_VAR)?;
+    match seed.parse() {
+        Ok(seed) => Some(seed),
+        Err(_) => {
+            warn!("Ignoring invalid seed: {}", seed);
+            None
+        }
+    }
+}
+
+fn game_rng_reseed(mut game_rng: ResMut<GameRng>) {
+    game_rng.reseed();
+    info!("Shift seed: {}", game_rng.seed());
+}
@@ -47,6 +47,18 @@ impl Interactable {
         )
     }
 
+    pub fn colliding(&self, other: &Interactable) -> bool {
+        self.shape.colliding(
+            self.translation + self.offset,
+            &other.shape,
+            other.translation + other.offset,
+        )
+    }
+
+    pub fn center(&self) -> Vec2 {
+        self.translation + self.offset + self.shape.center()
+    }
+
     pub fn drag_started(&self, game_input: &GameInput) -> Option<u64> {
         if let Some(drag) = game_input.drag_started() {
             if self.shape.colliding(
@@ -0,0 +1,122 @@
+use bevy::prelude::*;
+use bevy_spine::prelude::*;
+use serde::Deserialize;
+
+use super::{SecondOrderController, SpineSync2};
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub enum JiggleRigSystem {
+    Spawned,
+    Update,
+}
+
+pub struct JiggleRigPlugin;
+
+impl Plugin for JiggleRigPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(jiggle_rig_spawned.label(JiggleRigSystem::Spawned))
+            .add_system(
+                jiggle_rig_update
+                    .label(JiggleRigSystem::Update)
+                    .after_spine_sync::<SpineSync2>(),
+            );
+    }
+}
+
+/// Secondary motion for a skeleton, turned into a [`JiggleRig`] once the skeleton is ready.
+#[derive(Debug, Clone, Default, Deserialize, Component)]
+pub struct JiggleRigDefinition {
+    pub bones: Vec<JiggleBoneDefinition>,
+}
+
+/// A bone following the skeleton's position through a [`SecondOrderController`]. How far it lags
+/// behind, scaled by `strength`, drives the bone's `motion`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct JiggleBoneDefinition {
+    pub bone: String,
+    pub frequency: f32,
+    pub damping: f32,
+    pub response: f32,
+    pub strength: f32,
+    pub motion: JiggleMotion,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum JiggleMotion {
+    /// Moves the bone away from the direction it is lagging in.
+    Translate,
+    /// Shrinks the bone by how far it is lagging on each axis.
+    Squash,
+    /// Rotates the bone by the lag projected onto the given axis.
+    Rotate(f32, f32),
+}
+
+#[derive(Component)]
+pub struct JiggleRig {
+    bones: Vec<JiggleBone>,
+}
+
+struct JiggleBone {
+    handle: BoneHandle,
+    controller: SecondOrderController<Vec2>,
+    strength: f32,
+    motion: JiggleMotion,
+}
+
+fn jiggle_rig_spawned(
+    mut spine_ready_event: EventReader<SpineReadyEvent>,
+    mut commands: Commands,
+    rig_query: Query<(&JiggleRigDefinition, &Spine)>,
+) {
+    for event in spine_ready_event.iter() {
+        if let Ok((rig_definition, rig_spine)) = rig_query.get(event.entity) {
+            let mut bones = vec![];
+            for bone_definition in rig_definition.bones.iter() {
+                if let Some(bone) = rig_spine.skeleton.find_bone(&bone_definition.bone) {
+                    bones.push(JiggleBone {
+                        handle: bone.handle(),
+                        controller: SecondOrderController::new(
+                            Vec2::ZERO,
+                            bone_definition.frequency,
+                            bone_definition.damping,
+                            bone_definition.response,
+                        ),
+                        strength: bone_definition.strength,
+                        motion: bone_definition.motion,
+                    });
+                } else {
+                    warn!("Jiggle rig bone not found: {}", bone_definition.bone);
+                }
+            }
+            commands.entity(event.entity).insert(JiggleRig { bones });
+        }
+    }
+}
+
+fn jiggle_rig_update(
+    mut rig_query: Query<(&mut JiggleRig, &mut Spine, &GlobalTransform)>,
+    time: Res<Time>,
+) {
+    for (mut rig, mut rig_spine, rig_transform) in rig_query.iter_mut() {
+        let position = rig_transform.translation().truncate();
+        for bone in rig.bones.iter_mut() {
+            let lag =
+                (bone.controller.update(position, time.delta_seconds()) - position) * bone.strength;
+            let mut spine_bone = bone.handle.get_mut(&mut rig_spine.skeleton).unwrap();
+            match bone.motion {
+                JiggleMotion::Translate => {
+                    let original_position: Vec2 = spine_bone.data().position().into();
+                    spine_bone.set_position(original_position - lag);
+                }
+                JiggleMotion::Squash => {
+                    let original_scale: Vec2 = spine_bone.data().scale().into();
+                    spine_bone.set_scale(original_scale - lag.abs());
+                }
+                JiggleMotion::Rotate(x, y) => {
+                    let original_rotation = spine_bone.data().rotation();
+                    spine_bone.set_rotation(original_rotation + lag.dot(Vec2::new(x, y)));
+                }
+            }
+        }
+    }
+}
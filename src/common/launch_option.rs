@@ -0,0 +1,7 @@
+/// Reads `--name value` from the command line, falling back to an environment variable.
+pub fn launch_option(arg: &str, env_var: &str) -> Option<String> {
+    std::env::args()
+        .skip_while(|current| current != arg)
+        .nth(1)
+        .or_else(|| std::env::var(env_var).ok())
+}
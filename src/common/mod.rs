@@ -1,22 +1,34 @@
 mod asset_collection;
 mod clear_scene;
 mod collision;
+mod config;
 mod control;
 mod force_camera_ratio;
 mod game_input;
+mod game_input_replay;
+mod game_rng;
 mod interactable;
+mod jiggle_rig;
+mod launch_option;
 mod plugins;
+mod ron_asset;
 mod transform2;
 mod version;
 
 pub use asset_collection::*;
 pub use clear_scene::*;
 pub use collision::*;
+pub use config::*;
 pub use control::*;
 pub use force_camera_ratio::*;
 pub use game_input::*;
+pub use game_input_replay::*;
+pub use game_rng::*;
 pub use interactable::*;
+pub use jiggle_rig::*;
+pub use launch_option::*;
 pub use plugins::*;
+pub use ron_asset::*;
 pub use transform2::*;
 pub use version::*;
 
@@ -1,8 +1,9 @@
 use bevy::{app::PluginGroupBuilder, prelude::*};
 
 use super::{
-    ClearScenePlugin, ForceCameraRatioPlugin, GameInputPlugin, InteractablePlugin,
-    SpineSync2Plugin, Transform2Plugin, VersionPlugin,
+    ClearScenePlugin, ConfigPlugin, ForceCameraRatioPlugin, GameInputPlugin, GameInputReplayPlugin,
+    GameRngPlugin, InteractablePlugin, JiggleRigPlugin, SpineSync2Plugin, Transform2Plugin,
+    VersionPlugin,
 };
 
 pub struct CommonPlugins;
@@ -12,11 +13,15 @@ impl PluginGroup for CommonPlugins {
         let mut group = PluginGroupBuilder::start::<Self>();
         group = group.add(Transform2Plugin);
         group = group.add(SpineSync2Plugin::default());
+        group = group.add(JiggleRigPlugin);
         group = group.add(ForceCameraRatioPlugin);
         group = group.add(InteractablePlugin);
         group = group.add(GameInputPlugin);
+        group = group.add(GameInputReplayPlugin);
+        group = group.add(GameRngPlugin);
         group = group.add(VersionPlugin);
         group = group.add(ClearScenePlugin);
+        group = group.add(ConfigPlugin);
         group
     }
 }
@@ -134,7 +134,7 @@ fn update_transform2_recursive(
     transform_query: &mut Query<(&mut Transform, Option<&Transform2>, Option<&DepthLayer>)>,
     mut cumulative_depth: f32,
 ) {
-    if let Some((mut transform, transform2, depth_layer)) = transform_query.get_mut(entity).ok() {
+    if let Ok((mut transform, transform2, depth_layer)) = transform_query.get_mut(entity) {
         if let Some(transform2) = transform2 {
             transform.translation.x = transform2.translation.x;
             transform.translation.y = transform2.translation.y;
@@ -150,7 +150,7 @@ fn update_transform2_recursive(
         }
         cumulative_depth += transform.translation.z;
     }
-    if let Some(children) = children_query.get(entity).ok() {
+    if let Ok(children) = children_query.get(entity) {
         for child in children.iter() {
             update_transform2_recursive(*child, children_query, transform_query, cumulative_depth);
         }
@@ -1,15 +1,20 @@
 use bevy::prelude::*;
-use bevy_kira_audio::{Audio, AudioControl};
+use bevy_kira_audio::{AudioChannel, AudioControl};
 use lerp::Lerp;
-use rand::{random, seq::SliceRandom, thread_rng};
+use rand::{seq::SliceRandom, Rng};
 
-use crate::{AppState, AssetLibrary};
+use crate::{
+    common::{AnnouncementChannel, GameRng, MusicChannel},
+    AppState, AssetLibrary,
+};
 
 pub struct AmbiencePlugin;
 
 impl Plugin for AmbiencePlugin {
     fn build(&self, app: &mut App) {
-        app.add_system(ambience_update);
+        app.init_resource::<Ambience>()
+            .add_system_set(SystemSet::on_enter(AppState::Game).with_system(ambience_reset))
+            .add_system(ambience_update);
     }
 }
 
@@ -20,13 +25,15 @@ pub enum AmbienceKind {
     Announcement,
 }
 
-pub struct AmbienceUpdateLocal {
+/// What the radio and tannoy are up to. The countdown starts over with every shift.
+#[derive(Resource)]
+pub struct Ambience {
     first: bool,
     wait_time: f32,
     last_ambience: AmbienceKind,
 }
 
-impl Default for AmbienceUpdateLocal {
+impl Default for Ambience {
     fn default() -> Self {
         Self {
             first: true,
@@ -36,76 +43,76 @@ impl Default for AmbienceUpdateLocal {
     }
 }
 
+// the ambience music keeps playing from the menu, so only the countdown is reset
+fn ambience_reset(mut ambience: ResMut<Ambience>) {
+    *ambience = Ambience {
+        first: ambience.first,
+        ..Default::default()
+    };
+}
+
 fn ambience_update(
-    mut local: Local<AmbienceUpdateLocal>,
+    mut ambience: ResMut<Ambience>,
+    mut game_rng: ResMut<GameRng>,
     time: Res<Time>,
     asset_library: Res<AssetLibrary>,
-    audio: Res<Audio>,
+    music_channel: Res<AudioChannel<MusicChannel>>,
+    announcement_channel: Res<AudioChannel<AnnouncementChannel>>,
     state: Res<State<AppState>>,
 ) {
-    if local.first && *state.current() != AppState::Loading {
-        audio.play(asset_library.audio.ambience.clone()).looped();
-        local.first = false;
+    if ambience.first && *state.current() != AppState::Loading {
+        music_channel
+            .play(asset_library.audio.ambience.clone())
+            .looped();
+        ambience.first = false;
     }
     if *state.current() == AppState::Game {
-        local.wait_time -= time.delta_seconds();
+        ambience.wait_time -= time.delta_seconds();
     }
-    if local.wait_time <= 0. {
-        let mut rng = thread_rng();
-        if local.last_ambience == AmbienceKind::DingDong {
-            audio.play(
-                [
-                    asset_library.audio.announcement_1.clone(),
-                    asset_library.audio.announcement_2.clone(),
-                    asset_library.audio.announcement_3.clone(),
-                    asset_library.audio.announcement_4.clone(),
-                    asset_library.audio.announcement_5.clone(),
-                    asset_library.audio.announcement_6.clone(),
-                    asset_library.audio.announcement_7.clone(),
-                    asset_library.audio.announcement_8.clone(),
-                ]
-                .choose(&mut rng)
-                .unwrap()
-                .clone(),
-            );
-            local.last_ambience = AmbienceKind::Announcement;
-            local.wait_time = 14.;
+    if ambience.wait_time <= 0. {
+        let rng = game_rng.cosmetic();
+        if ambience.last_ambience == AmbienceKind::DingDong {
+            if let Some(sound) = asset_library.audio.announcements.choose(rng) {
+                announcement_channel.play(sound.clone());
+            }
+            ambience.last_ambience = AmbienceKind::Announcement;
+            ambience.wait_time = 14.;
         } else {
-            let ambience = if local.last_ambience == AmbienceKind::Song {
+            let kind = if ambience.last_ambience == AmbienceKind::Song {
                 AmbienceKind::DingDong
             } else {
                 *[AmbienceKind::Song, AmbienceKind::DingDong]
-                    .choose(&mut rng)
+                    .choose(rng)
                     .unwrap()
             };
-            match ambience {
-                AmbienceKind::Song => match [0, 1, 2].choose(&mut rng).unwrap() {
+            match kind {
+                AmbienceKind::Song => match [0, 1, 2].choose(rng).unwrap() {
                     0 => {
-                        audio.play(asset_library.audio.radio_tune_1.clone());
-                        local.wait_time = 30.;
+                        music_channel.play(asset_library.audio.radio_tune_1.clone());
+                        ambience.wait_time = 30.;
                     }
                     1 => {
-                        audio.play(asset_library.audio.radio_tune_2.clone());
-                        local.wait_time = 60.;
+                        music_channel.play(asset_library.audio.radio_tune_2.clone());
+                        ambience.wait_time = 60.;
                     }
                     2 => {
-                        audio.play(asset_library.audio.radio_tune_3.clone());
-                        local.wait_time = 60.;
+                        music_channel.play(asset_library.audio.radio_tune_3.clone());
+                        ambience.wait_time = 60.;
                     }
                     _ => unreachable!(),
                 },
                 AmbienceKind::DingDong => {
-                    audio.play(asset_library.audio.ding_dong.clone());
-                    local.wait_time = 3.5;
+                    announcement_channel.play(asset_library.audio.ding_dong.clone());
+                    ambience.wait_time = 3.5;
                 }
                 _ => {
                     unreachable!();
                 }
             }
-            local.last_ambience = ambience;
+            ambience.last_ambience = kind;
         }
-        if local.last_ambience != AmbienceKind::DingDong {
-            local.wait_time += 3.0_f32.lerp(30., random::<f32>());
+        if ambience.last_ambience != AmbienceKind::DingDong {
+            ambience.wait_time += 3.0_f32.lerp(30., rng.gen::<f32>());
         }
     }
 }
@@ -1,18 +1,34 @@
 use bevy::prelude::*;
-use bevy_kira_audio::{Audio, AudioControl};
+use bevy_kira_audio::{AudioChannel, AudioControl};
 use bevy_spine::prelude::*;
-use rand::{seq::SliceRandom, thread_rng};
+use rand::seq::SliceRandom;
 
 use crate::{
-    common::{Aabb, CollisionShape, GameInput, Interactable, SpineSync2, Transform2},
-    AssetLibrary,
+    common::{
+        CollisionShape, DepthLayer, GameInput, GameRng, Interactable, SfxChannel, SpineSync2,
+        Transform2,
+    },
+    AppState, AssetLibrary,
 };
 
 use super::{
-    Container, ContainerInserted, ContainerSlot, ContainerSystem, HealthDamageEvent, ProductSystem,
-    DEPTH_BAG,
+    BagCatalog, BagCatalogPlugin, BagClearedEvent, BagDefinition, Container, ContainerClear,
+    ContainerCleared, ContainerFilled, ContainerInserted, ContainerSystem, HealthDamageEvent,
+    ProductCatalog, ProductSystem, RuleSet, ShiftStats, DEPTH_BAG,
 };
 
+/// Bones whose names start with this are the slots of a bag, filled from the bottom up.
+pub const BAG_SLOT_PREFIX: &str = "slot";
+
+/// Where the button sending off a partially filled bag sits, relative to the bag.
+pub const BAG_SEND_BUTTON_OFFSET: Vec2 = Vec2::new(260., 60.);
+const BAG_SEND_BUTTON_SIZE: Vec2 = Vec2::new(100., 60.);
+const BAG_SEND_BUTTON_COLOR: Color = Color::rgb(0.25, 0.25, 0.25);
+const BAG_SEND_BUTTON_HOVER_COLOR: Color = Color::rgb(0.4, 0.4, 0.4);
+
+const BAG_LEAVE_DURATION: f32 = 0.4;
+const BAG_LEAVE_DISTANCE: f32 = 700.;
+
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
 pub enum BagSystem {
     Spawn,
@@ -20,14 +36,18 @@ pub enum BagSystem {
     Update,
     Hover,
     Inserted,
+    Filled,
+    SendButton,
     Clear,
+    Leave,
 }
 
 pub struct BagPlugin;
 
 impl Plugin for BagPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<BagSpawnEvent>()
+        app.add_plugin(BagCatalogPlugin)
+            .add_event::<BagSpawnEvent>()
             .add_system(bag_spawn.label(BagSystem::Spawn).before(SpineSystem::Load))
             .add_system(
                 bag_spawned
@@ -46,175 +66,349 @@ impl Plugin for BagPlugin {
                     .label(BagSystem::Inserted)
                     .after(ContainerSystem::Insert),
             )
-            .add_system(bag_clear.label(BagSystem::Clear).after(BagSystem::Inserted));
+            .add_system(
+                bag_filled
+                    .label(BagSystem::Filled)
+                    .after(ContainerSystem::Insert)
+                    .before(ContainerSystem::Clear),
+            )
+            .add_system_set(
+                SystemSet::on_update(AppState::Game).with_system(
+                    bag_send_button_update
+                        .label(BagSystem::SendButton)
+                        .before(ContainerSystem::Clear),
+                ),
+            )
+            .add_system(
+                bag_cleared
+                    .label(BagSystem::Clear)
+                    .after(ContainerSystem::Clear),
+            )
+            .add_system(bag_leave.label(BagSystem::Leave).after(BagSystem::Clear));
     }
 }
 
 #[derive(Default)]
 pub struct BagSpawnEvent {
     pub position: Vec2,
+    /// Id of the bag in the [`BagCatalog`].
+    pub id: String,
 }
 
 #[derive(Default, Component)]
-pub struct Bag;
+pub struct Bag {
+    /// The customer whose order goes in this bag.
+    pub customer: Option<Entity>,
+    pub definition: BagDefinition,
+}
+
+#[derive(Component)]
+struct BagSendButton;
+
+/// A sent off bag on its way out, carrying its products with it. A fresh bag of the same kind is
+/// put at `home` once it is gone.
+#[derive(Component)]
+struct BagLeaving {
+    id: String,
+    home: Vec2,
+    elapsed: f32,
+}
 
 fn bag_spawn(
     mut spawn_events: EventReader<BagSpawnEvent>,
     mut commands: Commands,
+    bag_catalog: Res<BagCatalog>,
     asset_library: Res<AssetLibrary>,
 ) {
     for event in spawn_events.iter() {
+        let definition = if let Some(definition) = bag_catalog.get(&event.id) {
+            definition
+        } else {
+            warn!("Bag {:?} is not in the catalog", event.id);
+            continue;
+        };
         commands
             .spawn(SpineBundle {
-                skeleton: asset_library.spines.bag.clone(),
+                skeleton: definition.skeleton.clone(),
                 ..Default::default()
             })
             .insert(Transform2::from_translation(event.position))
             .insert(DEPTH_BAG)
             .insert(SpineSync2)
-            .insert(Bag::default());
+            .insert(Bag {
+                customer: None,
+                definition: definition.clone(),
+            })
+            .with_children(|parent| {
+                parent
+                    .spawn(SpriteBundle {
+                        sprite: Sprite {
+                            custom_size: Some(BAG_SEND_BUTTON_SIZE),
+                            color: BAG_SEND_BUTTON_COLOR,
+                            ..Default::default()
+                        },
+                        visibility: Visibility { is_visible: false },
+                        ..Default::default()
+                    })
+                    .insert(Transform2::from_translation(BAG_SEND_BUTTON_OFFSET))
+                    .insert(DepthLayer::Inherit(0.05))
+                    .insert(Interactable::new(
+                        CollisionShape::Aabb {
+                            half_extents: BAG_SEND_BUTTON_SIZE * 0.5,
+                        },
+                        Vec2::ZERO,
+                    ))
+                    .insert(BagSendButton)
+                    .with_children(|parent| {
+                        parent
+                            .spawn(Text2dBundle {
+                                text: Text::from_section(
+                                    "Send",
+                                    TextStyle {
+                                        font: asset_library.fonts.default.clone(),
+                                        font_size: 32.,
+                                        color: Color::WHITE,
+                                    },
+                                )
+                                .with_alignment(TextAlignment::CENTER),
+                                ..Default::default()
+                            })
+                            .insert(Transform2::default())
+                            .insert(DepthLayer::Inherit(0.01));
+                    });
+            });
     }
 }
 
 fn bag_spawned(
     mut spine_ready_event: EventReader<SpineReadyEvent>,
     mut commands: Commands,
-    bag_query: Query<(Entity, &Spine), With<Bag>>,
+    bag_query: Query<(Entity, &Spine, &Bag)>,
 ) {
     for event in spine_ready_event.iter() {
-        if let Some((bag_entity, bag_spine)) = bag_query.get(event.entity).ok() {
-            if let Some(bounds) = bag_spine
-                .skeleton
-                .find_slot("bounds")
-                .unwrap()
-                .bounding_box_attachment()
+        if let Ok((bag_entity, bag_spine, bag)) = bag_query.get(event.entity) {
+            let bounds_slot = bag_spine.skeleton.find_slot("bounds");
+            if let Some(bounds) = bounds_slot
+                .as_ref()
+                .and_then(|slot| slot.bounding_box_attachment())
             {
-                let aabb = Aabb::new_from_vertices(
-                    &bounds
-                        .vertices2()
-                        .iter()
-                        .map(|vec| Vec2::from(*vec))
-                        .collect::<Vec<Vec2>>(),
-                )
-                .unwrap();
                 commands.entity(bag_entity).insert(Interactable::new(
-                    CollisionShape::Aabb {
-                        half_extents: aabb.half_extents,
-                    },
-                    aabb.translation,
+                    CollisionShape::polygon(bounds.vertices2().iter().map(|vec| Vec2::from(*vec))),
+                    Vec2::ZERO,
                 ));
+            } else {
+                warn!(
+                    "Bag {} has no bounds, so it can't be clicked",
+                    bag.definition.id
+                );
             }
-            let mut container = Container::default();
-            for slot_name in ["slot1", "slot2", "slot3"].into_iter() {
-                let slot_entity = *event.bones.get(slot_name).unwrap();
-                container.slots.push(ContainerSlot {
-                    slot_entity,
-                    product_entity: None,
-                });
+            // shorter names first keeps "slot10" above "slot9"
+            let mut slots = event
+                .bones
+                .iter()
+                .filter(|(bone_name, _)| bone_name.starts_with(BAG_SLOT_PREFIX))
+                .collect::<Vec<_>>();
+            slots.sort_by_key(|(bone_name, _)| (bone_name.len(), bone_name.as_str()));
+            if slots.len() < bag.definition.slots {
+                warn!(
+                    "Bag {:?} wants {} slots but its skeleton only has {}",
+                    bag.definition.id,
+                    bag.definition.slots,
+                    slots.len()
+                );
             }
-            commands.entity(bag_entity).insert(container);
+            commands.entity(bag_entity).insert(
+                Container::new(
+                    slots
+                        .into_iter()
+                        .take(bag.definition.slots)
+                        .map(|(_, bone_entity)| *bone_entity),
+                )
+                .with_accepts(bag.definition.accepts.clone()),
+            );
         }
     }
 }
 
 fn bag_update(
-    mut bag_query: Query<(&mut Spine, &Container, &Interactable), With<Bag>>,
+    mut bag_query: Query<(&mut Spine, &Bag, &Container, &Interactable)>,
+    rule_set: Res<RuleSet>,
+    product_catalog: Res<ProductCatalog>,
     game_input: Res<GameInput>,
 ) {
-    for (mut bag_spine, bag_container, bag_interactable) in bag_query.iter_mut() {
-        let mut color = if bag_container.valid_stack_with_candidates() {
-            Color::WHITE
-        } else {
-            Color::RED
-        };
+    for (mut bag_spine, bag, bag_container, bag_interactable) in bag_query.iter_mut() {
+        let mut color =
+            if bag_container.valid_with_candidates(rule_set.as_ref(), product_catalog.as_ref()) {
+                bag.definition.tint()
+            } else {
+                Color::RED
+            };
         if bag_interactable.hovered(game_input.as_ref()) {
             color *= 1.3;
         }
         color.set_a(1.);
-        *bag_spine.skeleton.find_slot_mut("bag").unwrap().color_mut() =
-            bevy_spine::Color::new_rgba(color.r(), color.g(), color.b(), color.a());
+        if let Some(mut slot) = bag_spine.skeleton.find_slot_mut("bag") {
+            *slot.color_mut() =
+                bevy_spine::Color::new_rgba(color.r(), color.g(), color.b(), color.a());
+        }
     }
 }
 
 fn bag_inserted(
     mut inserted_events: EventReader<ContainerInserted>,
     mut bag_query: Query<&mut Spine>,
-    audio: Res<Audio>,
+    mut game_rng: ResMut<GameRng>,
+    sfx_channel: Res<AudioChannel<SfxChannel>>,
     asset_library: Res<AssetLibrary>,
 ) {
     for event in inserted_events.iter() {
-        if let Some(mut bag_spine) = bag_query.get_mut(event.container).ok() {
+        if let Ok(mut bag_spine) = bag_query.get_mut(event.container) {
             let _ = bag_spine
                 .animation_state
                 .set_animation_by_name(0, "animation", false);
-            audio.play(
-                [
-                    asset_library.audio.bag_insert_1.clone(),
-                    asset_library.audio.bag_insert_2.clone(),
-                    asset_library.audio.bag_insert_3.clone(),
-                    asset_library.audio.bag_insert_4.clone(),
-                    asset_library.audio.bag_insert_5.clone(),
-                    asset_library.audio.bag_insert_6.clone(),
-                    asset_library.audio.bag_insert_7.clone(),
-                    asset_library.audio.bag_insert_8.clone(),
-                    asset_library.audio.bag_insert_9.clone(),
-                    asset_library.audio.bag_insert_10.clone(),
-                    asset_library.audio.bag_insert_11.clone(),
-                ]
-                .choose(&mut thread_rng())
-                .unwrap()
-                .clone(),
-            );
+            if let Some(sound) = asset_library.audio.bag_inserts.choose(game_rng.cosmetic()) {
+                sfx_channel.play(sound.clone());
+            }
+        }
+    }
+}
+
+// only shown while there is something in the bag to send off
+fn bag_send_button_update(
+    mut bag_send_button_query: Query<
+        (&Parent, &Interactable, &mut Sprite, &mut Visibility),
+        With<BagSendButton>,
+    >,
+    mut clear_events: EventWriter<ContainerClear>,
+    bag_query: Query<&Container, With<Bag>>,
+    game_input: Res<GameInput>,
+) {
+    for (
+        bag_send_button_parent,
+        bag_send_button_interactable,
+        mut bag_send_button_sprite,
+        mut bag_send_button_visibility,
+    ) in bag_send_button_query.iter_mut()
+    {
+        let bag_entity = bag_send_button_parent.get();
+        let sendable = bag_query
+            .get(bag_entity)
+            .map(|bag_container| bag_container.top().is_some())
+            .unwrap_or(false);
+        bag_send_button_visibility.is_visible = sendable;
+        if !sendable {
+            continue;
+        }
+        bag_send_button_sprite.color = if bag_send_button_interactable.hovered(game_input.as_ref())
+        {
+            BAG_SEND_BUTTON_HOVER_COLOR
+        } else {
+            BAG_SEND_BUTTON_COLOR
+        };
+        if bag_send_button_interactable
+            .drag_started(game_input.as_ref())
+            .is_some()
+        {
+            clear_events.send(ContainerClear {
+                container: bag_entity,
+            });
+        }
+    }
+}
+
+// full bags are sent off right away
+fn bag_filled(
+    mut filled_events: EventReader<ContainerFilled>,
+    mut clear_events: EventWriter<ContainerClear>,
+    bag_query: Query<(), With<Bag>>,
+) {
+    for event in filled_events.iter() {
+        if bag_query.contains(event.container) {
+            clear_events.send(ContainerClear {
+                container: event.container,
+            });
         }
     }
 }
 
 #[derive(Default)]
-struct BagClearLocal {
+struct BagClearedLocal {
     audio_track: usize,
 }
 
-// TODO: some of this logic should probably be controlled by container and not bag
-fn bag_clear(
-    mut bag_query: Query<&mut Container, With<Bag>>,
+#[allow(clippy::too_many_arguments)]
+fn bag_cleared(
+    mut container_cleared_events: EventReader<ContainerCleared>,
     mut commands: Commands,
-    mut local: Local<BagClearLocal>,
+    mut local: Local<BagClearedLocal>,
+    mut cleared_events: EventWriter<BagClearedEvent>,
     mut health_damage_events: EventWriter<HealthDamageEvent>,
+    mut shift_stats: ResMut<ShiftStats>,
+    bag_query: Query<(&Bag, &Transform2)>,
     asset_library: Res<AssetLibrary>,
-    audio: Res<Audio>,
+    sfx_channel: Res<AudioChannel<SfxChannel>>,
 ) {
-    for mut bag_container in bag_query.iter_mut() {
-        if bag_container.slots.len() > 0
-            && bag_container
-                .slots
-                .get(bag_container.slots.len() - 1)
-                .unwrap()
-                .product_entity
-                .is_some()
-        {
-            for slot in bag_container.slots.iter_mut() {
-                if let Some(product_entity) = slot.product_entity {
-                    commands.entity(product_entity).despawn_recursive();
-                }
-                slot.product_entity = None;
+    for event in container_cleared_events.iter() {
+        let (bag, bag_transform) = if let Ok(bag) = bag_query.get(event.container) {
+            bag
+        } else {
+            continue;
+        };
+        if event.partial {
+            // a sent off bag takes its products along as it leaves
+            commands
+                .entity(event.container)
+                .remove::<Bag>()
+                .remove::<Container>()
+                .remove::<Interactable>()
+                .insert(BagLeaving {
+                    id: bag.definition.id.clone(),
+                    home: bag_transform.translation,
+                    elapsed: 0.,
+                });
+        } else {
+            for product_entity in event.product_entities.iter() {
+                commands.entity(*product_entity).despawn_recursive();
             }
-            if bag_container.valid_stack() {
-                audio.play(
-                    [
-                        asset_library.audio.bag_clear_success_1.clone(),
-                        asset_library.audio.bag_clear_success_2.clone(),
-                        asset_library.audio.bag_clear_success_3.clone(),
-                    ]
-                    .into_iter()
-                    .nth(local.audio_track)
-                    .unwrap()
-                    .clone(),
-                );
-                local.audio_track = (local.audio_track + 1) % 3;
-            } else {
-                health_damage_events.send_default();
+        }
+        cleared_events.send(BagClearedEvent {
+            container: event.container,
+            products: event.products.clone(),
+            valid: event.valid,
+            partial: event.partial,
+        });
+        if event.valid {
+            shift_stats.bags_cleared += 1;
+            shift_stats.products_bagged += event.products.len() as u32;
+            let sounds = &asset_library.audio.bag_clear_successes;
+            if !sounds.is_empty() {
+                sfx_channel.play(sounds[local.audio_track % sounds.len()].clone());
+                local.audio_track = (local.audio_track + 1) % sounds.len();
             }
-            bag_container.products = vec![];
+        } else {
+            shift_stats.bags_failed += 1;
+            health_damage_events.send_default();
+        }
+    }
+}
+
+fn bag_leave(
+    mut bag_query: Query<(Entity, &mut BagLeaving, &mut Transform2)>,
+    mut commands: Commands,
+    mut spawn_events: EventWriter<BagSpawnEvent>,
+    time: Res<Time>,
+) {
+    for (bag_entity, mut bag_leaving, mut bag_transform) in bag_query.iter_mut() {
+        bag_leaving.elapsed += time.delta_seconds();
+        let progress = (bag_leaving.elapsed / BAG_LEAVE_DURATION).min(1.);
+        bag_transform.translation =
+            bag_leaving.home - Vec2::Y * BAG_LEAVE_DISTANCE * progress * progress;
+        if progress >= 1. {
+            commands.entity(bag_entity).despawn_recursive();
+            spawn_events.send(BagSpawnEvent {
+                position: bag_leaving.home,
+                id: bag_leaving.id.clone(),
+            });
         }
     }
 }
@@ -0,0 +1,203 @@
+use bevy::{prelude::*, reflect::TypeUuid, utils::HashMap};
+use bevy_spine::prelude::*;
+use serde::Deserialize;
+
+use crate::{common::RonAssetPlugin, AssetLibrary};
+
+use super::ProductAttribute;
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub enum BagCatalogSystem {
+    Load,
+}
+
+pub struct BagCatalogPlugin;
+
+impl Plugin for BagCatalogPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(RonAssetPlugin::<BagCatalog>::new(&["bags.ron"]))
+            .init_resource::<BagCatalog>()
+            .add_system(bag_catalog_load.label(BagCatalogSystem::Load));
+    }
+}
+
+/// Every kind of bag that can sit on the desk, as listed in `data/standard.bags.ron`.
+#[derive(Debug, Clone, Default, Deserialize, TypeUuid, Resource)]
+#[uuid = "5d7c2a8e-93b1-4e0f-8a6d-1c4e2b9f7a30"]
+pub struct BagCatalog {
+    pub bags: Vec<BagDefinition>,
+    /// Ids of the bags put on the desk at the start of a shift, from left to right.
+    pub lineup: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BagDefinition {
+    pub id: String,
+    /// Number of `slot*` bones filled before the bag is sent off, counted from the bottom.
+    pub slots: usize,
+    /// Products need at least one of these attributes to belong in the bag. Anything goes if
+    /// this is empty.
+    #[serde(default)]
+    pub accepts: Vec<ProductAttribute>,
+    /// Directory containing `skeleton.json` and `skeleton.atlas`.
+    pub spine: String,
+    #[serde(default = "bag_definition_default_tint")]
+    pub tint: (f32, f32, f32),
+    /// Loaded from `spine` once the catalog is made active.
+    #[serde(skip)]
+    pub skeleton: Handle<SkeletonData>,
+}
+
+fn bag_definition_default_tint() -> (f32, f32, f32) {
+    (1., 1., 1.)
+}
+
+impl BagDefinition {
+    pub fn tint(&self) -> Color {
+        let (r, g, b) = self.tint;
+        Color::rgb(r, g, b)
+    }
+}
+
+impl BagCatalog {
+    /// The catalog shipped with the game, for running without an asset server.
+    pub fn builtin() -> Self {
+        ron::de::from_str(include_str!("../../assets/data/standard.bags.ron")).unwrap()
+    }
+
+    pub fn get(&self, id: &str) -> Option<&BagDefinition> {
+        self.bags.iter().find(|definition| definition.id == id)
+    }
+
+    /// Checks for bags that are listed twice or hold nothing, and lineups with unknown bags.
+    pub fn validate(&self) -> Result<(), String> {
+        for (i, definition) in self.bags.iter().enumerate() {
+            if self.bags[..i]
+                .iter()
+                .any(|other_definition| other_definition.id == definition.id)
+            {
+                return Err(format!("{:?} is listed more than once", definition.id));
+            }
+            if definition.slots == 0 {
+                return Err(format!("{:?} has no slots", definition.id));
+            }
+        }
+        if self.lineup.is_empty() {
+            return Err("the lineup is empty".to_string());
+        }
+        for id in self.lineup.iter() {
+            if self.get(id).is_none() {
+                return Err(format!("{:?} is in the lineup but not listed", id));
+            }
+        }
+        Ok(())
+    }
+
+    /// Weak handles to the skeleton files of every bag, for tracking load progress.
+    pub fn handles(&self) -> Vec<HandleUntyped> {
+        self.bags
+            .iter()
+            .flat_map(|definition| {
+                [
+                    HandleUntyped::weak(format!("{}/skeleton.json", definition.spine).into()),
+                    HandleUntyped::weak(format!("{}/skeleton.atlas", definition.spine).into()),
+                ]
+            })
+            .collect()
+    }
+
+    /// Weak handles to the skeleton of every bag, made once the catalog is active.
+    pub fn skeletons(&self) -> Vec<Handle<SkeletonData>> {
+        self.bags
+            .iter()
+            .map(|definition| definition.skeleton.clone_weak())
+            .collect()
+    }
+}
+
+fn bag_catalog_load(
+    mut asset_events: EventReader<AssetEvent<BagCatalog>>,
+    mut bag_catalog: ResMut<BagCatalog>,
+    // keyed by `spine` directory, so reloading the catalog reuses the skeletons already made
+    mut skeleton_handles: Local<HashMap<String, Handle<SkeletonData>>>,
+    mut skeletons: ResMut<Assets<SkeletonData>>,
+    bag_catalogs: Res<Assets<BagCatalog>>,
+    asset_server: Res<AssetServer>,
+    asset_library: Res<AssetLibrary>,
+) {
+    for event in asset_events.iter() {
+        match event {
+            AssetEvent::Created { handle } | AssetEvent::Modified { handle } => {
+                if *handle == asset_library.data.bags {
+                    if let Some(loaded_catalog) = bag_catalogs.get(handle) {
+                        if let Err(error) = loaded_catalog.validate() {
+                            error!("Ignoring invalid bag catalog: {}", error);
+                            continue;
+                        }
+                        *bag_catalog = loaded_catalog.clone();
+                        for definition in bag_catalog.bags.iter_mut() {
+                            definition.skeleton = skeleton_handles
+                                .entry(definition.spine.clone())
+                                .or_insert_with(|| {
+                                    skeletons.add(SkeletonData::new_from_json(
+                                        asset_server
+                                            .load(format!("{}/skeleton.json", definition.spine)),
+                                        asset_server
+                                            .load(format!("{}/skeleton.atlas", definition.spine)),
+                                    ))
+                                })
+                                .clone();
+                        }
+                    }
+                }
+            }
+            AssetEvent::Removed { .. } => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::{BagCatalog, Container, ProductAttribute};
+
+    #[test]
+    fn lineup() {
+        let catalog = BagCatalog::builtin();
+        assert!(!catalog.lineup.is_empty());
+        for id in catalog.lineup.iter() {
+            assert!(catalog.get(id).is_some(), "{} is not in the catalog", id);
+        }
+    }
+
+    #[test]
+    fn cooler_accepts_cold() {
+        let catalog = BagCatalog::builtin();
+        let container =
+            |id: &str| Container::default().with_accepts(catalog.get(id).unwrap().accepts.clone());
+        let cooler = container("cooler");
+        assert!(cooler.accepts(ProductAttribute::Cold.bitwise()));
+        assert!(!cooler.accepts(ProductAttribute::Hot.bitwise()));
+        assert!(container("paper").accepts(ProductAttribute::Hot.bitwise()));
+    }
+
+    #[test]
+    fn builtin_is_valid() {
+        assert_eq!(BagCatalog::builtin().validate(), Ok(()));
+    }
+
+    #[test]
+    fn invalid() {
+        let mut catalog = BagCatalog::builtin();
+        catalog.bags[0].slots = 0;
+        assert!(catalog.validate().is_err());
+
+        let mut catalog = BagCatalog::builtin();
+        let duplicate = catalog.bags[0].clone();
+        catalog.bags.push(duplicate);
+        assert!(catalog.validate().is_err());
+
+        let mut catalog = BagCatalog::builtin();
+        catalog.lineup.push("missing".to_string());
+        assert!(catalog.validate().is_err());
+    }
+}
@@ -3,13 +3,18 @@ use bevy::prelude::*;
 use crate::{
     common::{GameInput, Interactable},
     game::ProductDrag,
+    AppState,
 };
 
-use super::{Product, ProductKind, ProductSystem};
+use super::{
+    Product, ProductAttribute, ProductAttributes, ProductCatalog, ProductKind, ProductSystem,
+    RuleSet,
+};
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
 pub enum ContainerSystem {
     Insert,
+    Clear,
     Drag,
 }
 
@@ -19,15 +24,28 @@ impl Plugin for ContainerPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<ContainerInsert>()
             .add_event::<ContainerInserted>()
+            .add_event::<ContainerFilled>()
+            .add_event::<ContainerRemoved>()
+            .add_event::<ContainerClear>()
+            .add_event::<ContainerCleared>()
             .add_system(
                 container_insert
                     .label(ContainerSystem::Insert)
                     .after(ProductSystem::Drop),
             )
-            .add_system(container_drag.label(ContainerSystem::Drag));
+            .add_system(
+                container_clear
+                    .label(ContainerSystem::Clear)
+                    .after(ContainerSystem::Insert),
+            )
+            .add_system_set(
+                SystemSet::on_update(AppState::Game)
+                    .with_system(container_drag.label(ContainerSystem::Drag)),
+            );
     }
 }
 
+/// Asks for a product to be put in the lowest free slot of a container.
 pub struct ContainerInsert {
     pub container: Entity,
     pub product: Entity,
@@ -39,108 +57,246 @@ pub struct ContainerInserted {
     pub product: Entity,
 }
 
+/// Sent when an insert fills the top slot of a container.
+pub struct ContainerFilled {
+    pub container: Entity,
+}
+
+/// Sent when a product is taken out of a container by being inserted into another one.
+pub struct ContainerRemoved {
+    pub container: Entity,
+    pub product: Entity,
+}
+
+/// Asks for everything in a container to be taken out and judged. Empty containers are left
+/// alone.
+pub struct ContainerClear {
+    pub container: Entity,
+}
+
+/// Sent once a container has been emptied. The products are no longer tracked by the container
+/// but are still children of its slots, for whoever reacts to decide what happens to them.
+pub struct ContainerCleared {
+    pub container: Entity,
+    pub product_entities: Vec<Entity>,
+    pub products: Vec<ProductKind>,
+    /// Whether the products stacked up by the rules and all belonged in the container.
+    pub valid: bool,
+    /// Whether the container was cleared before its top slot was filled.
+    pub partial: bool,
+}
+
 #[derive(Default, Component)]
 pub struct Container {
     pub slots: Vec<ContainerSlot>,
-    pub products: Vec<ProductKind>,
+    /// Products need at least one of these attributes to belong in the container. Anything goes
+    /// if this is empty.
+    pub accepts: Vec<ProductAttribute>,
     pub drop_candidates: Vec<ProductKind>,
 }
 
 impl Container {
-    pub fn valid_stack(&self) -> bool {
-        ProductKind::valid_stack(&self.products)
+    pub fn new(slot_entities: impl IntoIterator<Item = Entity>) -> Self {
+        Self {
+            slots: slot_entities
+                .into_iter()
+                .map(|slot_entity| ContainerSlot {
+                    slot_entity,
+                    product_entity: None,
+                    product_kind: None,
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_accepts(mut self, accepts: Vec<ProductAttribute>) -> Self {
+        self.accepts = accepts;
+        self
+    }
+
+    /// Puts `product` in the lowest free slot, returning that slot. Nothing happens if the
+    /// container is full or already holds the product.
+    pub fn insert(&mut self, product: Entity, kind: ProductKind) -> Option<Entity> {
+        if self.contains(product) {
+            return None;
+        }
+        let slot = self
+            .slots
+            .iter_mut()
+            .find(|slot| slot.product_entity.is_none())?;
+        slot.product_entity = Some(product);
+        slot.product_kind = Some(kind);
+        Some(slot.slot_entity)
+    }
+
+    /// Takes `product` out of its slot, returning whether it was in the container.
+    pub fn remove(&mut self, product: Entity) -> bool {
+        let mut removed = false;
+        for slot in self.slots.iter_mut() {
+            if slot.product_entity == Some(product) {
+                slot.product_entity = None;
+                slot.product_kind = None;
+                removed = true;
+            }
+        }
+        removed
+    }
+
+    /// The product in the highest filled slot.
+    pub fn top(&self) -> Option<Entity> {
+        self.slots.iter().rev().find_map(|slot| slot.product_entity)
+    }
+
+    pub fn contains(&self, product: Entity) -> bool {
+        self.slots
+            .iter()
+            .any(|slot| slot.product_entity == Some(product))
+    }
+
+    /// Kinds of the products stacked from the bottom slot up, stopping at the first free slot.
+    pub fn products(&self) -> Vec<ProductKind> {
+        self.slots
+            .iter()
+            .map_while(|slot| slot.product_kind)
+            .collect()
+    }
+
+    pub fn full(&self) -> bool {
+        self.slots
+            .last()
+            .map(|slot| slot.product_entity.is_some())
+            .unwrap_or(false)
+    }
+
+    pub fn accepts(&self, attributes: ProductAttributes) -> bool {
+        self.accepts.is_empty()
+            || self
+                .accepts
+                .iter()
+                .any(|attribute| attributes.contains(attribute.bitwise()))
+    }
+
+    pub fn valid_stack(&self, rule_set: &RuleSet, product_catalog: &ProductCatalog) -> bool {
+        rule_set.valid_stack(product_catalog, &self.products())
+    }
+
+    /// Whether the stack follows the rules and every product belongs in the container.
+    pub fn valid(&self, rule_set: &RuleSet, product_catalog: &ProductCatalog) -> bool {
+        self.valid_stack(rule_set, product_catalog)
+            && self
+                .products()
+                .iter()
+                .all(|kind| self.accepts(product_catalog.attributes(*kind)))
     }
 
-    pub fn valid_stack_with_candidates(&self) -> bool {
+    /// Like [`Container::valid`], but also checking each product that is about to be dropped in.
+    pub fn valid_with_candidates(
+        &self,
+        rule_set: &RuleSet,
+        product_catalog: &ProductCatalog,
+    ) -> bool {
+        let products = self.products();
         for drop_candidate in self.drop_candidates.iter() {
-            if !ProductKind::valid_stack(&[self.products.clone(), vec![*drop_candidate]].concat()) {
+            if !self.accepts(product_catalog.attributes(*drop_candidate))
+                || !rule_set.valid_stack(
+                    product_catalog,
+                    &[products.clone(), vec![*drop_candidate]].concat(),
+                )
+            {
                 return false;
             }
         }
-        ProductKind::valid_stack(&self.products)
+        self.valid(rule_set, product_catalog)
+    }
+
+    /// Empties every slot, returning the products that were in them from the bottom up.
+    fn take_all(&mut self) -> Vec<Entity> {
+        self.slots
+            .iter_mut()
+            .filter_map(|slot| {
+                slot.product_kind = None;
+                slot.product_entity.take()
+            })
+            .collect()
     }
 }
 
 pub struct ContainerSlot {
     pub slot_entity: Entity,
     pub product_entity: Option<Entity>,
+    product_kind: Option<ProductKind>,
 }
 
 fn container_insert(
     mut commands: Commands,
     mut insert_events: EventReader<ContainerInsert>,
     mut inserted_events: EventWriter<ContainerInserted>,
+    mut filled_events: EventWriter<ContainerFilled>,
+    mut removed_events: EventWriter<ContainerRemoved>,
     mut container_query: Query<(Entity, &mut Container)>,
     product_query: Query<&Product>,
 ) {
     for event in insert_events.iter() {
-        let mut inserted = false;
-        if let Some((_, mut container)) = container_query.get_mut(event.container).ok() {
-            for slot in container.slots.iter_mut() {
-                if slot
-                    .product_entity
-                    .map(|product| product == event.product)
-                    .unwrap_or(false)
-                {
-                    break;
-                }
-                if slot.product_entity.is_none() {
-                    slot.product_entity = Some(event.product);
-                    commands.entity(slot.slot_entity).add_child(event.product);
-                    inserted_events.send(ContainerInserted {
-                        container: event.container,
-                        slot: slot.slot_entity,
+        let product = if let Ok(product) = product_query.get(event.product) {
+            product
+        } else {
+            continue;
+        };
+        let slot = if let Ok((_, mut container)) = container_query.get_mut(event.container) {
+            container
+                .insert(event.product, product.kind())
+                .map(|slot| (slot, container.full()))
+        } else {
+            None
+        };
+        if let Some((slot, full)) = slot {
+            commands.entity(slot).add_child(event.product);
+            inserted_events.send(ContainerInserted {
+                container: event.container,
+                slot,
+                product: event.product,
+            });
+            if full {
+                filled_events.send(ContainerFilled {
+                    container: event.container,
+                });
+            }
+            // a product only ever sits in one container
+            for (container_entity, mut container) in container_query.iter_mut() {
+                if container_entity != event.container && container.remove(event.product) {
+                    removed_events.send(ContainerRemoved {
+                        container: container_entity,
                         product: event.product,
                     });
-                    inserted = true;
-                    break;
                 }
             }
-            if inserted {
-                let mut products = vec![];
-                for slot in container.slots.iter() {
-                    if let Some(product) = slot
-                        .product_entity
-                        .and_then(|entity| product_query.get(entity).ok())
-                    {
-                        products.push(product.kind());
-                    } else {
-                        break;
-                    }
-                }
-                container.products = products;
-            }
         }
-        if inserted {
-            for (container_entity, mut container) in container_query.iter_mut() {
-                let mut updated = false;
-                if container_entity != event.container {
-                    for mut slot in container.slots.iter_mut() {
-                        if slot
-                            .product_entity
-                            .map(|entity| entity == event.product)
-                            .unwrap_or(false)
-                        {
-                            slot.product_entity = None;
-                            updated = true;
-                        }
-                    }
-                }
-                if updated {
-                    let mut products = vec![];
-                    for slot in container.slots.iter() {
-                        if let Some(product) = slot
-                            .product_entity
-                            .and_then(|entity| product_query.get(entity).ok())
-                        {
-                            products.push(product.kind());
-                        } else {
-                            break;
-                        }
-                    }
-                    container.products = products;
-                }
+    }
+}
+
+fn container_clear(
+    mut clear_events: EventReader<ContainerClear>,
+    mut cleared_events: EventWriter<ContainerCleared>,
+    mut container_query: Query<&mut Container>,
+    rule_set: Res<RuleSet>,
+    product_catalog: Res<ProductCatalog>,
+) {
+    for event in clear_events.iter() {
+        if let Ok(mut container) = container_query.get_mut(event.container) {
+            let products = container.products();
+            if products.is_empty() {
+                continue;
             }
+            let valid = container.valid(rule_set.as_ref(), product_catalog.as_ref());
+            let partial = !container.full();
+            cleared_events.send(ContainerCleared {
+                container: event.container,
+                product_entities: container.take_all(),
+                products,
+                valid,
+                partial,
+            });
         }
     }
 }
@@ -152,12 +308,41 @@ fn container_drag(
 ) {
     for (container, container_interactable) in container_query.iter() {
         if let Some(drag_id) = container_interactable.drag_started(game_input.as_ref()) {
-            for slot in container.slots.iter().rev() {
-                if let Some(product_entity) = slot.product_entity {
-                    commands.entity(product_entity).insert(ProductDrag(drag_id));
-                    break;
-                }
+            if let Some(product_entity) = container.top() {
+                commands.entity(product_entity).insert(ProductDrag(drag_id));
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use crate::game::{Container, ProductKind};
+
+    #[test]
+    fn slot_bookkeeping() {
+        let mut container = Container::new((0..3).map(Entity::from_raw));
+        let (jerky, cinder) = (Entity::from_raw(10), Entity::from_raw(11));
+        assert_eq!(
+            container.insert(jerky, ProductKind::new("jerky")),
+            Some(Entity::from_raw(0))
+        );
+        assert_eq!(container.insert(jerky, ProductKind::new("jerky")), None);
+        assert_eq!(
+            container.insert(cinder, ProductKind::new("cinder")),
+            Some(Entity::from_raw(1))
+        );
+        assert_eq!(container.top(), Some(cinder));
+        assert_eq!(
+            container.products(),
+            vec![ProductKind::new("jerky"), ProductKind::new("cinder")]
+        );
+        assert!(!container.full());
+        assert!(container.remove(cinder));
+        assert!(!container.remove(cinder));
+        assert_eq!(container.top(), Some(jerky));
+        assert_eq!(container.products(), vec![ProductKind::new("jerky")]);
+    }
+}
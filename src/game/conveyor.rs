@@ -1,9 +1,14 @@
 use bevy::prelude::*;
 use bevy_spine::prelude::*;
 
-use crate::common::{SpineSync2, Transform2};
+use crate::{
+    common::{SpineSync2, Transform2},
+    AppState,
+};
 
-use super::HealthDamageEvent;
+use super::{Difficulty, DifficultySystem, HealthDamageEvent, ShiftStats};
+
+const CONVEYOR_PATH_CURVE_SEGMENTS: usize = 32;
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
 pub enum ConveyorSystem {
@@ -15,64 +20,238 @@ pub struct ConveyorPlugin;
 
 impl Plugin for ConveyorPlugin {
     fn build(&self, app: &mut App) {
-        app.add_system(
-            conveyor_update
-                .label(ConveyorSystem::Update)
-                .during_spine_sync::<SpineSync2>(),
-        )
-        .add_system(
-            conveyor_item_update
-                .label(ConveyorSystem::ItemUpdate)
-                .after(ConveyorSystem::Update),
+        app.add_system_set(
+            SystemSet::on_update(AppState::Game)
+                .with_system(
+                    conveyor_update
+                        .label(ConveyorSystem::Update)
+                        .after(DifficultySystem::Update)
+                        .during_spine_sync::<SpineSync2>(),
+                )
+                .with_system(
+                    conveyor_item_update
+                        .label(ConveyorSystem::ItemUpdate)
+                        .after(ConveyorSystem::Update),
+                ),
         );
     }
 }
 
+/// A belt that carries [`ConveyorItem`]s along its `path`.
 #[derive(Component)]
 pub struct Conveyor {
-    pub x: f32,
+    pub path: ConveyorPath,
+    /// Current speed along the path, following [`Difficulty::conveyor_speed`].
     pub speed: f32,
+    /// How fast this belt runs compared to the difficulty's conveyor speed.
+    pub speed_scale: f32,
+    /// Whether new products are put on this belt, rather than only being handed over from others.
+    pub source: bool,
+    /// Belt that items are handed over to at the end of the path. Items run off the end and are
+    /// missed if there is none.
+    pub next: Option<Entity>,
+}
+
+impl Conveyor {
+    pub fn new(path: ConveyorPath) -> Self {
+        Self {
+            path,
+            speed: 100.,
+            speed_scale: 1.,
+            source: true,
+            next: None,
+        }
+    }
 }
 
+// the desk's belt as authored in its skeleton, for when the skeleton isn't loaded
 impl Default for Conveyor {
     fn default() -> Self {
-        Self { x: 0., speed: 100. }
+        Self::new(ConveyorPath::new(vec![
+            Vec2::new(-1066., -387.),
+            Vec2::new(600., -387.),
+        ]))
+    }
+}
+
+/// Scrolls the belt artwork of a [`Conveyor`] so it appears to move with its items.
+#[derive(Component, Default)]
+pub struct ConveyorScroll {
+    pub x: f32,
+}
+
+/// Polyline through world space that items follow, starting at the first point. Positions are
+/// looked up by distance travelled, so items keep a steady pace along curves too.
+#[derive(Debug, Clone, Default)]
+pub struct ConveyorPath {
+    points: Vec<Vec2>,
+    length: f32,
+}
+
+impl ConveyorPath {
+    pub fn new(points: Vec<Vec2>) -> Self {
+        let length = points
+            .windows(2)
+            .map(|segment| segment[0].distance(segment[1]))
+            .sum();
+        Self { points, length }
+    }
+
+    /// Flattens an open Spine path, given as the vertices of a path attachment. Every knot has
+    /// three vertices: the incoming handle, the point itself and the outgoing handle.
+    pub fn from_bezier(vertices: impl IntoIterator<Item = Vec2>) -> Self {
+        let vertices = vertices.into_iter().collect::<Vec<Vec2>>();
+        let knots = vertices.chunks_exact(3).collect::<Vec<&[Vec2]>>();
+        let mut points = knots.first().map(|knot| vec![knot[1]]).unwrap_or_default();
+        for curve in knots.windows(2) {
+            let (start, start_handle) = (curve[0][1], curve[0][2]);
+            let (end_handle, end) = (curve[1][0], curve[1][1]);
+            for i in 1..=CONVEYOR_PATH_CURVE_SEGMENTS {
+                let t = i as f32 / CONVEYOR_PATH_CURVE_SEGMENTS as f32;
+                let u = 1. - t;
+                points.push(
+                    start * u * u * u
+                        + start_handle * 3. * u * u * t
+                        + end_handle * 3. * u * t * t
+                        + end * t * t * t,
+                );
+            }
+        }
+        Self::new(points)
+    }
+
+    pub fn length(&self) -> f32 {
+        self.length
+    }
+
+    /// Point `distance` along the path, clamped to its ends.
+    pub fn position(&self, distance: f32) -> Vec2 {
+        let mut remaining = distance.max(0.);
+        for segment in self.points.windows(2) {
+            let segment_length = segment[0].distance(segment[1]);
+            if remaining <= segment_length && segment_length > 0. {
+                return segment[0].lerp(segment[1], remaining / segment_length);
+            }
+            remaining -= segment_length;
+        }
+        self.points.last().copied().unwrap_or_default()
+    }
+
+    /// The same path travelled in the opposite direction.
+    pub fn reversed(&self) -> Self {
+        Self::new(self.points.iter().rev().copied().collect())
     }
 }
 
-fn conveyor_update(mut conveyor_query: Query<(&mut Conveyor, &mut Transform2)>, time: Res<Time>) {
-    for (mut conveyor, mut conveyor_transform) in conveyor_query.iter_mut() {
-        conveyor.x = (conveyor.x + time.delta_seconds() * conveyor.speed) % 1566.811;
-        conveyor_transform.translation.x = conveyor.x - 16.111;
+fn conveyor_update(
+    mut conveyor_query: Query<(
+        &mut Conveyor,
+        Option<(&mut ConveyorScroll, &mut Transform2)>,
+    )>,
+    difficulty: Res<Difficulty>,
+    time: Res<Time>,
+) {
+    for (mut conveyor, conveyor_scroll) in conveyor_query.iter_mut() {
+        conveyor.speed = difficulty.conveyor_speed * conveyor.speed_scale;
+        if let Some((mut conveyor_scroll, mut conveyor_transform)) = conveyor_scroll {
+            conveyor_scroll.x =
+                (conveyor_scroll.x + time.delta_seconds() * conveyor.speed) % 1566.811;
+            conveyor_transform.translation.x = conveyor_scroll.x - 16.111;
+        }
     }
 }
 
-#[derive(Component, Default)]
+#[derive(Component)]
 pub struct ConveyorItem {
+    /// The belt carrying this item.
+    pub conveyor: Entity,
+    /// Distance travelled along the belt's path.
     pub progress: f32,
     pub position: Vec2,
 }
 
+impl ConveyorItem {
+    pub fn new(conveyor: Entity) -> Self {
+        Self {
+            conveyor,
+            progress: 0.,
+            position: Vec2::ZERO,
+        }
+    }
+}
+
 fn conveyor_item_update(
     mut conveyor_item_query: Query<(Entity, &mut ConveyorItem)>,
     mut commands: Commands,
     mut health_damage_events: EventWriter<HealthDamageEvent>,
+    mut shift_stats: ResMut<ShiftStats>,
     conveyor_query: Query<(&Conveyor, &GlobalTransform)>,
     time: Res<Time>,
 ) {
-    let (conveyor, conveyor_transform) =
-        if let Some((conveyor, conveyor_transform)) = conveyor_query.get_single().ok() {
-            (conveyor, conveyor_transform)
-        } else {
-            return;
-        };
-    for (conveyor_entity, mut conveyor_item) in conveyor_item_query.iter_mut() {
+    for (conveyor_item_entity, mut conveyor_item) in conveyor_item_query.iter_mut() {
+        let (conveyor, conveyor_transform) =
+            if let Ok(conveyor) = conveyor_query.get(conveyor_item.conveyor) {
+                conveyor
+            } else {
+                continue;
+            };
         let (conveyor_scale, _, _) = conveyor_transform.to_scale_rotation_translation();
         conveyor_item.progress += (time.delta_seconds() * conveyor.speed) * conveyor_scale.x;
-        conveyor_item.position = Vec2::new(-1066. + conveyor_item.progress, -387.);
-        if conveyor_item.progress > 1666. {
-            commands.entity(conveyor_entity).despawn_recursive();
-            health_damage_events.send_default();
+        if conveyor_item.progress > conveyor.path.length() {
+            if let Some(next) = conveyor.next.filter(|next| conveyor_query.contains(*next)) {
+                conveyor_item.progress -= conveyor.path.length();
+                conveyor_item.conveyor = next;
+            } else {
+                commands.entity(conveyor_item_entity).despawn_recursive();
+                health_damage_events.send_default();
+                shift_stats.products_missed += 1;
+                continue;
+            }
+        }
+        if let Ok((conveyor, _)) = conveyor_query.get(conveyor_item.conveyor) {
+            conveyor_item.position = conveyor.path.position(conveyor_item.progress);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use crate::game::ConveyorPath;
+
+    #[test]
+    fn polyline_position() {
+        let path = ConveyorPath::new(vec![
+            Vec2::new(0., 0.),
+            Vec2::new(100., 0.),
+            Vec2::new(100., 50.),
+        ]);
+        assert_eq!(path.length(), 150.);
+        assert_eq!(path.position(-10.), Vec2::new(0., 0.));
+        assert_eq!(path.position(50.), Vec2::new(50., 0.));
+        assert_eq!(path.position(125.), Vec2::new(100., 25.));
+        assert_eq!(path.position(200.), Vec2::new(100., 50.));
+        assert_eq!(path.reversed().position(25.), Vec2::new(100., 25.));
+    }
+
+    #[test]
+    fn bezier_arc_length() {
+        // a quarter circle of radius 100, with the usual handle length for four segments
+        let handle = 100. * 0.552_284_8;
+        let path = ConveyorPath::from_bezier([
+            Vec2::new(0., 0.),
+            Vec2::new(100., 0.),
+            Vec2::new(100., handle),
+            Vec2::new(handle, 100.),
+            Vec2::new(0., 100.),
+            Vec2::new(0., 0.),
+        ]);
+        let quarter = std::f32::consts::FRAC_PI_2 * 100.;
+        assert!((path.length() - quarter).abs() < 0.5);
+        let middle = path.position(path.length() * 0.5);
+        assert!((middle.length() - 100.).abs() < 0.5);
+        assert!((middle.x - middle.y).abs() < 0.5);
+        assert!(path.position(path.length()).distance(Vec2::new(0., 100.)) < 0.01);
+    }
+}
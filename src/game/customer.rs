@@ -1,28 +1,67 @@
+use std::collections::VecDeque;
+
 use bevy::prelude::*;
 use bevy_spine::prelude::*;
+use lerp::Lerp;
+use rand::Rng;
 
 use crate::{
     common::{SpineSync2, Transform2},
-    AssetLibrary,
+    AppState, AssetLibrary,
+};
+
+use super::{
+    Bag, BagClearedEvent, BagSystem, Container, Difficulty, HealthDamageEvent, ProductCatalog,
+    ProductKind, RuleSet, DEPTH_CUSTOMER, DEPTH_CUSTOMER_BUBBLE, DEPTH_CUSTOMER_SILHOUETTE,
 };
 
-use super::{DEPTH_CUSTOMER, DEPTH_CUSTOMER_SILHOUETTE};
+const CUSTOMER_PATIENCE: f32 = 45.;
+const CUSTOMER_LEAVE_X: f32 = 1100.;
+const CUSTOMER_BUBBLE_OFFSET: Vec2 = Vec2::new(0., 330.);
+const CUSTOMER_ORDER_ATTEMPTS: usize = 10;
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
 pub enum CustomerSystem {
+    Reset,
     Spawn,
     Spawned,
     Update,
+    Patience,
+    BagCleared,
+    BubbleUpdate,
 }
 
 pub struct CustomerPlugin;
 
 impl Plugin for CustomerPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<CustomerSpawnEvent>()
+        app.init_resource::<OrderQueue>()
+            .add_event::<CustomerSpawnEvent>()
+            .add_system_set(
+                SystemSet::on_enter(AppState::Game)
+                    .with_system(customer_reset.label(CustomerSystem::Reset)),
+            )
             .add_system(customer_spawn.label(CustomerSystem::Spawn))
             .add_system(customer_spawned.label(CustomerSystem::Spawned))
-            .add_system(customer_update.label(CustomerSystem::Update));
+            .add_system(customer_update.label(CustomerSystem::Update))
+            .add_system_set(
+                SystemSet::on_update(AppState::Game).with_system(
+                    customer_patience
+                        .label(CustomerSystem::Patience)
+                        .after(CustomerSystem::Update),
+                ),
+            )
+            .add_system(
+                customer_bag_cleared
+                    .label(CustomerSystem::BagCleared)
+                    .after(BagSystem::Clear),
+            )
+            .add_system(
+                customer_bubble_update
+                    .label(CustomerSystem::BubbleUpdate)
+                    .after(CustomerSystem::Patience)
+                    .after(CustomerSystem::BagCleared),
+            );
     }
 }
 
@@ -32,21 +71,142 @@ pub struct CustomerSpawnEvent {
     pub scale: f32,
     pub speed: f32,
     pub silhouette: bool,
+    /// Products the customer wants bagged. Customers without an order, or who find every bag
+    /// taken, just walk past.
+    pub order: Vec<ProductKind>,
+    /// Bag the order was chosen for, or the leftmost free one if `None` or already taken.
+    pub bag: Option<Entity>,
 }
 
 #[derive(Component)]
 pub struct Customer {
     speed: f32,
     silhouette: bool,
+    state: CustomerState,
+    reaction: Option<&'static str>,
+}
+
+impl Customer {
+    pub fn waiting(&self) -> bool {
+        self.state == CustomerState::Waiting
+    }
+
+    pub fn leaving(&self) -> bool {
+        self.state == CustomerState::Leaving
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CustomerState {
+    /// Walking up to the bag at `x`.
+    Arriving {
+        x: f32,
+    },
+    Waiting,
+    Leaving,
+}
+
+/// What a customer is waiting for, and which bag it should end up in. Removed once the customer
+/// has been served or gave up.
+#[derive(Component)]
+pub struct CustomerOrder {
+    pub products: Vec<ProductKind>,
+    pub bag: Entity,
+    /// Seconds left before the customer gives up, drained only while waiting at the desk.
+    pub patience: f32,
+}
+
+impl CustomerOrder {
+    /// Whether `products` are exactly the ones ordered, in any order.
+    pub fn matches(&self, products: &[ProductKind]) -> bool {
+        let mut ordered = self
+            .products
+            .iter()
+            .map(|kind| kind.id())
+            .collect::<Vec<_>>();
+        let mut bagged = products.iter().map(|kind| kind.id()).collect::<Vec<_>>();
+        ordered.sort_unstable();
+        bagged.sort_unstable();
+        ordered == bagged
+    }
+}
+
+/// Ordered products that have yet to be put on the conveyor, oldest order first.
+#[derive(Default, Resource)]
+pub struct OrderQueue {
+    products: VecDeque<(Entity, ProductKind)>,
+}
+
+impl OrderQueue {
+    pub fn push(&mut self, customer: Entity, products: &[ProductKind]) {
+        self.products
+            .extend(products.iter().map(|kind| (customer, *kind)));
+    }
+
+    pub fn pop(&mut self) -> Option<ProductKind> {
+        self.products.pop_front().map(|(_, kind)| kind)
+    }
+
+    /// Drops whatever is left of a customer's order.
+    pub fn cancel(&mut self, customer: Entity) {
+        self.products.retain(|(entity, _)| *entity != customer);
+    }
+
+    pub fn clear(&mut self) {
+        self.products.clear();
+    }
+}
+
+/// Text above a customer showing their order, and how they felt about it once served.
+#[derive(Component)]
+struct CustomerBubble;
+
+/// Picks an order that fills `bag` with products it accepts, preferring products that can all
+/// share it.
+pub fn customer_choose_order<R: Rng + ?Sized>(
+    difficulty: &Difficulty,
+    rule_set: &RuleSet,
+    product_catalog: &ProductCatalog,
+    bag: &Container,
+    rng: &mut R,
+) -> Vec<ProductKind> {
+    let mut order = vec![];
+    for _ in 0..CUSTOMER_ORDER_ATTEMPTS {
+        order = (0..bag.slots.len())
+            .filter_map(|_| {
+                difficulty.choose_product_where(product_catalog, rng, |kind| {
+                    bag.accepts(product_catalog.attributes(kind))
+                })
+            })
+            .collect::<Vec<ProductKind>>();
+        let stackable = order.iter().enumerate().all(|(i, kind)| {
+            order[..i].iter().all(|other_kind| {
+                let attributes = product_catalog.attributes(*kind);
+                let other_attributes = product_catalog.attributes(*other_kind);
+                rule_set.can_stack(attributes, other_attributes)
+                    || rule_set.can_stack(other_attributes, attributes)
+            })
+        });
+        if stackable {
+            break;
+        }
+    }
+    order
+}
+
+fn customer_reset(mut order_queue: ResMut<OrderQueue>) {
+    order_queue.clear();
 }
 
 fn customer_spawn(
     mut spawn_events: EventReader<CustomerSpawnEvent>,
     mut commands: Commands,
+    mut bag_query: Query<(Entity, &mut Bag, &Transform2)>,
+    mut order_queue: ResMut<OrderQueue>,
     asset_library: Res<AssetLibrary>,
 ) {
     for event in spawn_events.iter() {
-        commands
+        let customer_entity = commands
             .spawn(SpineBundle {
                 skeleton: asset_library.spines.customer.clone(),
                 ..Default::default()
@@ -60,10 +220,67 @@ fn customer_spawn(
                 DEPTH_CUSTOMER
             })
             .insert(SpineSync2)
-            .insert(Customer {
-                speed: event.speed,
-                silhouette: event.silhouette,
+            .with_children(|parent| {
+                parent
+                    .spawn(Text2dBundle {
+                        text: Text::from_section(
+                            "",
+                            TextStyle {
+                                font: asset_library.fonts.default.clone(),
+                                font_size: 40.,
+                                color: Color::WHITE,
+                            },
+                        )
+                        .with_alignment(TextAlignment::BOTTOM_CENTER),
+                        ..Default::default()
+                    })
+                    .insert(Transform2::from_translation(CUSTOMER_BUBBLE_OFFSET))
+                    .insert(DEPTH_CUSTOMER_BUBBLE)
+                    .insert(CustomerBubble);
+            })
+            .id();
+
+        // the leftmost free bag goes to the customer who arrives first
+        let free_bag = if event.order.is_empty() || event.silhouette {
+            None
+        } else {
+            let chosen_bag = event
+                .bag
+                .filter(|bag_entity| {
+                    bag_query
+                        .get(*bag_entity)
+                        .map(|(_, bag, _)| bag.customer.is_none())
+                        .unwrap_or(false)
+                })
+                .or_else(|| {
+                    bag_query
+                        .iter()
+                        .filter(|(_, bag, _)| bag.customer.is_none())
+                        .min_by(|(_, _, a), (_, _, b)| a.translation.x.total_cmp(&b.translation.x))
+                        .map(|(bag_entity, _, _)| bag_entity)
+                });
+            chosen_bag.and_then(|bag_entity| bag_query.get_mut(bag_entity).ok())
+        };
+        let state = if let Some((bag_entity, mut bag, bag_transform)) = free_bag {
+            bag.customer = Some(customer_entity);
+            order_queue.push(customer_entity, &event.order);
+            commands.entity(customer_entity).insert(CustomerOrder {
+                products: event.order.clone(),
+                bag: bag_entity,
+                patience: CUSTOMER_PATIENCE,
             });
+            CustomerState::Arriving {
+                x: bag_transform.translation.x,
+            }
+        } else {
+            CustomerState::Leaving
+        };
+        commands.entity(customer_entity).insert(Customer {
+            speed: event.speed,
+            silhouette: event.silhouette,
+            state,
+            reaction: None,
+        });
     }
 }
 
@@ -72,7 +289,7 @@ fn customer_spawned(
     mut spine_query: Query<(&mut Spine, &Customer)>,
 ) {
     for event in spine_ready_event.iter() {
-        if let Some((mut spine, customer)) = spine_query.get_mut(event.entity).ok() {
+        if let Ok((mut spine, customer)) = spine_query.get_mut(event.entity) {
             let _ = spine
                 .animation_state
                 .set_animation_by_name(0, "animation", true);
@@ -86,14 +303,137 @@ fn customer_spawned(
 }
 
 fn customer_update(
-    mut customer_query: Query<(Entity, &mut Transform2, &Customer)>,
+    mut customer_query: Query<(Entity, &mut Transform2, &mut Customer)>,
+    mut commands: Commands,
+    time: Res<Time>,
+) {
+    for (customer_entity, mut customer_transform, mut customer) in customer_query.iter_mut() {
+        let step = time.delta_seconds() * customer.speed;
+        match customer.state {
+            CustomerState::Arriving { x } => {
+                customer_transform.translation.x = (customer_transform.translation.x + step).min(x);
+                if customer_transform.translation.x >= x {
+                    customer.state = CustomerState::Waiting;
+                }
+            }
+            CustomerState::Waiting => {}
+            CustomerState::Leaving => {
+                customer_transform.translation.x += step;
+                if customer_transform.translation.x > CUSTOMER_LEAVE_X {
+                    commands.entity(customer_entity).despawn_recursive();
+                }
+            }
+        }
+    }
+}
+
+fn customer_patience(
+    mut customer_query: Query<(Entity, &mut Customer, &mut CustomerOrder)>,
+    mut bag_query: Query<&mut Bag>,
     mut commands: Commands,
+    mut health_damage_events: EventWriter<HealthDamageEvent>,
+    mut order_queue: ResMut<OrderQueue>,
     time: Res<Time>,
 ) {
-    for (customer_entity, mut customer_transform, customer) in customer_query.iter_mut() {
-        customer_transform.translation.x += time.delta_seconds() * customer.speed;
-        if customer_transform.translation.x > 1100. {
-            commands.entity(customer_entity).despawn_recursive();
+    for (customer_entity, mut customer, mut customer_order) in customer_query.iter_mut() {
+        if !customer.waiting() {
+            continue;
+        }
+        customer_order.patience -= time.delta_seconds();
+        if customer_order.patience <= 0. {
+            health_damage_events.send_default();
+            customer_leave(
+                customer_entity,
+                customer.as_mut(),
+                customer_order.as_ref(),
+                "Too slow!",
+                &mut bag_query,
+                &mut commands,
+                order_queue.as_mut(),
+            );
+        }
+    }
+}
+
+fn customer_bag_cleared(
+    mut cleared_events: EventReader<BagClearedEvent>,
+    mut customer_query: Query<(&mut Customer, &CustomerOrder)>,
+    mut bag_query: Query<&mut Bag>,
+    mut commands: Commands,
+    mut health_damage_events: EventWriter<HealthDamageEvent>,
+    mut order_queue: ResMut<OrderQueue>,
+) {
+    for event in cleared_events.iter() {
+        let customer_entity = match bag_query.get(event.container) {
+            Ok(Bag {
+                customer: Some(customer_entity),
+                ..
+            }) => *customer_entity,
+            _ => continue,
+        };
+        if let Ok((mut customer, customer_order)) = customer_query.get_mut(customer_entity) {
+            let reaction = if customer_order.matches(&event.products) {
+                "Thanks!"
+            } else {
+                // a ruined bag has already cost health
+                if event.valid {
+                    health_damage_events.send_default();
+                }
+                "Wrong order!"
+            };
+            customer_leave(
+                customer_entity,
+                customer.as_mut(),
+                customer_order,
+                reaction,
+                &mut bag_query,
+                &mut commands,
+                order_queue.as_mut(),
+            );
+        }
+    }
+}
+
+fn customer_leave(
+    customer_entity: Entity,
+    customer: &mut Customer,
+    customer_order: &CustomerOrder,
+    reaction: &'static str,
+    bag_query: &mut Query<&mut Bag>,
+    commands: &mut Commands,
+    order_queue: &mut OrderQueue,
+) {
+    customer.state = CustomerState::Leaving;
+    customer.reaction = Some(reaction);
+    if let Ok(mut bag) = bag_query.get_mut(customer_order.bag) {
+        bag.customer = None;
+    }
+    order_queue.cancel(customer_entity);
+    commands.entity(customer_entity).remove::<CustomerOrder>();
+}
+
+fn customer_bubble_update(
+    mut bubble_query: Query<(&Parent, &mut Text), With<CustomerBubble>>,
+    customer_query: Query<(&Customer, Option<&CustomerOrder>)>,
+) {
+    for (bubble_parent, mut bubble_text) in bubble_query.iter_mut() {
+        if let Ok((customer, customer_order)) = customer_query.get(bubble_parent.get()) {
+            let section = &mut bubble_text.sections[0];
+            if let Some(reaction) = customer.reaction {
+                section.value = reaction.to_string();
+                section.style.color = Color::WHITE;
+            } else if let Some(customer_order) = customer_order {
+                section.value = customer_order
+                    .products
+                    .iter()
+                    .map(|kind| kind.id().replace('_', " "))
+                    .collect::<Vec<String>>()
+                    .join("\n");
+                // fades to red as the customer runs out of patience
+                let patience = (customer_order.patience / CUSTOMER_PATIENCE).clamp(0., 1.);
+                let fade = 0.2_f32.lerp(1., patience);
+                section.style.color = Color::rgb(1., fade, fade);
+            }
         }
     }
 }
@@ -7,8 +7,14 @@ pub const DEPTH_CUSTOMER_SILHOUETTE: DepthLayer = DepthLayer::Background(0.05);
 
 pub const DEPTH_DESK: DepthLayer = DepthLayer::Foreground(0.);
 pub const DEPTH_BAG: DepthLayer = DepthLayer::Foreground(0.1);
+pub const DEPTH_FOCUS: DepthLayer = DepthLayer::Foreground(0.15);
 pub const DEPTH_PRODUCT: DepthLayer = DepthLayer::Foreground(0.2);
 pub const DEPTH_PRODUCT_DRAGGING: DepthLayer = DepthLayer::Foreground(0.3);
+pub const DEPTH_CUSTOMER_BUBBLE: DepthLayer = DepthLayer::Foreground(0.35);
 pub const DEPTH_PRODUCT_ICON: DepthLayer = DepthLayer::Inherit(0.1);
 
 pub const DEPTH_HEALTH: DepthLayer = DepthLayer::Foreground(0.4);
+
+pub const DEPTH_RESULTS: DepthLayer = DepthLayer::Foreground(0.5);
+
+pub const DEPTH_PAUSE: DepthLayer = DepthLayer::Foreground(0.6);
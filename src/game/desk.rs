@@ -1,12 +1,17 @@
 use bevy::prelude::*;
-use bevy_spine::prelude::*;
+use bevy_spine::{
+    prelude::*,
+    rusty_spine::{
+        c::spPathAttachment, c_interface::NewFromPtr, AttachmentType, PathAttachment, Slot,
+    },
+};
 
 use crate::{
     common::{SpineSync2, Transform2},
     AssetLibrary,
 };
 
-use super::{Conveyor, DEPTH_DESK};
+use super::{Conveyor, ConveyorPath, ConveyorScroll, DEPTH_DESK};
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
 pub enum DeskSystem {
@@ -55,14 +60,46 @@ fn desk_spawn(
 fn desk_spawned(
     mut spine_ready_event: EventReader<SpineReadyEvent>,
     mut commands: Commands,
-    desk_query: Query<&Desk>,
+    desk_query: Query<(&Spine, &Transform2), With<Desk>>,
 ) {
     for event in spine_ready_event.iter() {
-        if desk_query.contains(event.entity) {
+        if let Ok((desk_spine, desk_transform)) = desk_query.get(event.entity) {
+            // the path hangs off the desk bone, which sits at the skeleton's origin
+            let conveyor = if let Some(vertices) = desk_spine
+                .skeleton
+                .find_slot("conveyor-path")
+                .and_then(|slot| slot_path_vertices(&slot))
+            {
+                Conveyor::new(ConveyorPath::from_bezier(vertices.into_iter().map(
+                    |vertex| desk_transform.translation + vertex * desk_transform.scale,
+                )))
+            } else {
+                warn!("Desk skeleton has no conveyor path");
+                Conveyor::default()
+            };
             commands
                 .entity(*event.bones.get("conveyor").unwrap())
-                .insert(Conveyor::default())
+                .insert(conveyor)
+                .insert(ConveyorScroll::default())
                 .insert(Transform2::default());
         }
     }
 }
+
+/// Vertices of the path attached to `slot`, in the slot bone's space. `rusty_spine` has no typed
+/// accessor for path attachments, so the attachment is only reinterpreted once its type is known.
+fn slot_path_vertices(slot: &Slot) -> Option<Vec<Vec2>> {
+    let attachment = slot.attachment()?;
+    if attachment.attachment_type() != AttachmentType::Path {
+        return None;
+    }
+    // the attachment was checked to be a path and outlives `path`
+    let path =
+        unsafe { PathAttachment::new_from_ptr(attachment.c_ptr() as *const spPathAttachment) };
+    Some(
+        path.vertices2()
+            .iter()
+            .map(|vertex| Vec2::from(*vertex))
+            .collect(),
+    )
+}
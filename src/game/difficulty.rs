@@ -0,0 +1,245 @@
+use bevy::{prelude::*, reflect::TypeUuid, utils::HashMap};
+use lerp::Lerp;
+use rand::{seq::SliceRandom, Rng};
+use serde::Deserialize;
+
+use crate::{common::RonAssetPlugin, AssetLibrary};
+
+use super::{ProductCatalog, ProductKind, Score, ShiftStats, ShiftStatsSystem};
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub enum DifficultySystem {
+    Load,
+    Update,
+}
+
+pub struct DifficultyPlugin;
+
+impl Plugin for DifficultyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(RonAssetPlugin::<DifficultyCurve>::new(&["difficulty.ron"]))
+            .init_resource::<DifficultyCurve>()
+            .init_resource::<Difficulty>()
+            .add_system(difficulty_load.label(DifficultySystem::Load))
+            .add_system(
+                difficulty_update
+                    .label(DifficultySystem::Update)
+                    .after(DifficultySystem::Load)
+                    .after(ShiftStatsSystem::Update),
+            );
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub enum DifficultyInput {
+    #[default]
+    Time,
+    Score,
+}
+
+/// Pacing of a shift, keyed by elapsed shift time or score depending on `input`.
+/// Values between keys are linearly interpolated and clamped to the first and last key.
+#[derive(Debug, Clone, Deserialize, TypeUuid, Resource)]
+#[uuid = "7b6345e5-cd4e-402c-8627-5930c3ca6d39"]
+pub struct DifficultyCurve {
+    #[serde(default)]
+    pub input: DifficultyInput,
+    pub keys: Vec<DifficultyKey>,
+}
+
+impl Default for DifficultyCurve {
+    fn default() -> Self {
+        Self {
+            input: DifficultyInput::Time,
+            keys: vec![DifficultyKey {
+                at: 0.,
+                conveyor_speed: 100.,
+                spawn_interval: 1.5,
+                customer_interval: 17.,
+                product_weights: HashMap::default(),
+            }],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DifficultyKey {
+    pub at: f32,
+    pub conveyor_speed: f32,
+    pub spawn_interval: f32,
+    pub customer_interval: f32,
+    /// Relative chance of each product being spawned. Unlisted products have a weight of 1.
+    #[serde(default)]
+    pub product_weights: HashMap<ProductKind, f32>,
+}
+
+impl DifficultyKey {
+    fn product_weight(&self, kind: ProductKind) -> f32 {
+        self.product_weights.get(&kind).copied().unwrap_or(1.)
+    }
+}
+
+impl DifficultyCurve {
+    pub fn sample(&self, at: f32, product_catalog: &ProductCatalog) -> Difficulty {
+        let next_index = self
+            .keys
+            .iter()
+            .position(|key| key.at > at)
+            .unwrap_or(self.keys.len());
+        let (from, to) = match (
+            self.keys.get(next_index.wrapping_sub(1)),
+            self.keys.get(next_index),
+        ) {
+            (Some(from), Some(to)) => (from, to),
+            (Some(key), None) | (None, Some(key)) => (key, key),
+            (None, None) => return Difficulty::default(),
+        };
+        let amount = if to.at > from.at {
+            ((at - from.at) / (to.at - from.at)).clamp(0., 1.)
+        } else {
+            0.
+        };
+        Difficulty {
+            conveyor_speed: from.conveyor_speed.lerp(to.conveyor_speed, amount),
+            spawn_interval: from.spawn_interval.lerp(to.spawn_interval, amount),
+            customer_interval: from.customer_interval.lerp(to.customer_interval, amount),
+            product_weights: product_catalog
+                .kinds()
+                .map(|kind| {
+                    (
+                        kind,
+                        from.product_weight(kind)
+                            .lerp(to.product_weight(kind), amount)
+                            .max(0.),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The current pacing values, sampled from the active [`DifficultyCurve`] every frame.
+#[derive(Debug, Clone, Resource)]
+pub struct Difficulty {
+    pub conveyor_speed: f32,
+    pub spawn_interval: f32,
+    pub customer_interval: f32,
+    pub product_weights: HashMap<ProductKind, f32>,
+}
+
+impl Default for Difficulty {
+    fn default() -> Self {
+        Self {
+            conveyor_speed: 100.,
+            spawn_interval: 1.5,
+            customer_interval: 17.,
+            product_weights: HashMap::default(),
+        }
+    }
+}
+
+impl Difficulty {
+    /// Picks a product from the catalog, or nothing if the catalog is still empty.
+    pub fn choose_product<R: Rng + ?Sized>(
+        &self,
+        product_catalog: &ProductCatalog,
+        rng: &mut R,
+    ) -> Option<ProductKind> {
+        self.choose_product_where(product_catalog, rng, |_| true)
+    }
+
+    /// Like [`Difficulty::choose_product`], but only out of the products matching `predicate`.
+    pub fn choose_product_where<R: Rng + ?Sized>(
+        &self,
+        product_catalog: &ProductCatalog,
+        rng: &mut R,
+        predicate: impl Fn(ProductKind) -> bool,
+    ) -> Option<ProductKind> {
+        let kinds = product_catalog
+            .kinds()
+            .filter(|kind| predicate(*kind))
+            .collect::<Vec<ProductKind>>();
+        if let Ok(kind) = kinds.choose_weighted(rng, |kind| {
+            self.product_weights.get(kind).copied().unwrap_or(1.)
+        }) {
+            Some(*kind)
+        } else {
+            kinds.choose(rng).copied()
+        }
+    }
+}
+
+fn difficulty_load(
+    mut asset_events: EventReader<AssetEvent<DifficultyCurve>>,
+    mut difficulty_curve: ResMut<DifficultyCurve>,
+    difficulty_curves: Res<Assets<DifficultyCurve>>,
+    asset_library: Res<AssetLibrary>,
+) {
+    for event in asset_events.iter() {
+        match event {
+            AssetEvent::Created { handle } | AssetEvent::Modified { handle } => {
+                if *handle == asset_library.data.difficulty {
+                    if let Some(loaded_curve) = difficulty_curves.get(handle) {
+                        *difficulty_curve = loaded_curve.clone();
+                    }
+                }
+            }
+            AssetEvent::Removed { .. } => {}
+        }
+    }
+}
+
+fn difficulty_update(
+    mut difficulty: ResMut<Difficulty>,
+    difficulty_curve: Res<DifficultyCurve>,
+    product_catalog: Res<ProductCatalog>,
+    shift_stats: Res<ShiftStats>,
+    score: Res<Score>,
+) {
+    let at = match difficulty_curve.input {
+        DifficultyInput::Time => shift_stats.time,
+        DifficultyInput::Score => score.points as f32,
+    };
+    *difficulty = difficulty_curve.sample(at, product_catalog.as_ref());
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::utils::HashMap;
+
+    use crate::game::{
+        DifficultyCurve, DifficultyInput, DifficultyKey, ProductCatalog, ProductKind,
+    };
+
+    fn key(at: f32, conveyor_speed: f32) -> DifficultyKey {
+        DifficultyKey {
+            at,
+            conveyor_speed,
+            spawn_interval: 1.,
+            customer_interval: 10.,
+            product_weights: HashMap::default(),
+        }
+    }
+
+    #[test]
+    fn sample() {
+        let mut curve = DifficultyCurve {
+            input: DifficultyInput::Time,
+            keys: vec![key(0., 100.), key(60., 200.)],
+        };
+        curve.keys[1]
+            .product_weights
+            .insert(ProductKind::new("beans"), 3.);
+        let catalog = ProductCatalog::builtin();
+
+        assert_eq!(curve.sample(-10., &catalog).conveyor_speed, 100.);
+        assert_eq!(curve.sample(0., &catalog).conveyor_speed, 100.);
+        assert_eq!(curve.sample(30., &catalog).conveyor_speed, 150.);
+        assert_eq!(curve.sample(60., &catalog).conveyor_speed, 200.);
+        assert_eq!(curve.sample(1000., &catalog).conveyor_speed, 200.);
+
+        let weights = curve.sample(30., &catalog).product_weights;
+        assert_eq!(weights[&ProductKind::new("beans")], 2.);
+        assert_eq!(weights[&ProductKind::new("bread")], 1.);
+    }
+}
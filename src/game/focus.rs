@@ -0,0 +1,294 @@
+use bevy::prelude::*;
+
+use crate::{
+    common::{GameInput, GameInputDragSource, GameInputVirtual, Interactable, Transform2},
+    AppState,
+};
+
+use super::{
+    Bag, ContainerClear, ContainerSystem, ConveyorItem, Product, ProductDrag, ProductSystem,
+    DEPTH_FOCUS,
+};
+
+const FOCUS_CONVEYOR_SIZE: Vec2 = Vec2::new(180., 180.);
+const FOCUS_BAG_SIZE: Vec2 = Vec2::new(360., 420.);
+const FOCUS_COLOR: Color = Color::rgba(1., 1., 1., 0.2);
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub enum FocusSystem {
+    Reset,
+    Update,
+    CursorSpawn,
+    CursorUpdate,
+}
+
+/// Keyboard and gamepad controls. A focus cursor snaps between the products on the conveyor and
+/// the bags, and picks up and drops products through [`GameInputVirtual`].
+pub struct FocusPlugin;
+
+impl Plugin for FocusPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Focus>()
+            .add_event::<FocusCursorSpawnEvent>()
+            .add_system_set(
+                SystemSet::on_enter(AppState::Game)
+                    .with_system(focus_reset.label(FocusSystem::Reset)),
+            )
+            .add_system_set(
+                SystemSet::on_update(AppState::Game).with_system(
+                    focus_update
+                        .label(FocusSystem::Update)
+                        .before(ProductSystem::Drop)
+                        .before(ProductSystem::Drag)
+                        .before(ContainerSystem::Drag)
+                        .before(ContainerSystem::Clear),
+                ),
+            )
+            .add_system(focus_cursor_spawn.label(FocusSystem::CursorSpawn))
+            .add_system(
+                focus_cursor_update
+                    .label(FocusSystem::CursorUpdate)
+                    .after(FocusSystem::Update),
+            );
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FocusRow {
+    #[default]
+    Conveyor,
+    Bags,
+}
+
+#[derive(Default, Resource)]
+pub struct Focus {
+    pub active: bool,
+    pub row: FocusRow,
+    pub entity: Option<Entity>,
+    pub position: Vec2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FocusAction {
+    Left,
+    Right,
+    Up,
+    Down,
+    PickUp,
+    Drop,
+    /// Sends off the focused bag, like its send button.
+    Send,
+}
+
+impl FocusAction {
+    const ALL: [FocusAction; 7] = [
+        FocusAction::Left,
+        FocusAction::Right,
+        FocusAction::Up,
+        FocusAction::Down,
+        FocusAction::PickUp,
+        FocusAction::Drop,
+        FocusAction::Send,
+    ];
+
+    fn keys(&self) -> &'static [KeyCode] {
+        match *self {
+            FocusAction::Left => &[KeyCode::Left, KeyCode::A],
+            FocusAction::Right => &[KeyCode::Right, KeyCode::D],
+            FocusAction::Up => &[KeyCode::Up, KeyCode::W],
+            FocusAction::Down => &[KeyCode::Down, KeyCode::S],
+            FocusAction::PickUp => &[KeyCode::Space],
+            FocusAction::Drop => &[KeyCode::Return],
+            FocusAction::Send => &[KeyCode::Back],
+        }
+    }
+
+    fn button(&self) -> GamepadButtonType {
+        match *self {
+            FocusAction::Left => GamepadButtonType::DPadLeft,
+            FocusAction::Right => GamepadButtonType::DPadRight,
+            FocusAction::Up => GamepadButtonType::DPadUp,
+            FocusAction::Down => GamepadButtonType::DPadDown,
+            FocusAction::PickUp => GamepadButtonType::South,
+            FocusAction::Drop => GamepadButtonType::East,
+            FocusAction::Send => GamepadButtonType::West,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct FocusCursorSpawnEvent;
+
+#[derive(Component)]
+pub struct FocusCursor;
+
+fn focus_reset(mut focus: ResMut<Focus>, mut game_input_virtual: ResMut<GameInputVirtual>) {
+    *focus = Focus::default();
+    *game_input_virtual = GameInputVirtual::default();
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn focus_update(
+    mut focus: ResMut<Focus>,
+    mut game_input_virtual: ResMut<GameInputVirtual>,
+    mut clear_events: EventWriter<ContainerClear>,
+    product_query: Query<
+        (Entity, &Interactable),
+        (With<Product>, With<ConveyorItem>, Without<ProductDrag>),
+    >,
+    bag_query: Query<(Entity, &Interactable), With<Bag>>,
+    game_input: Res<GameInput>,
+    keys: Res<Input<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+) {
+    let just_pressed = |action: FocusAction| {
+        keys.any_just_pressed(action.keys().iter().copied())
+            || gamepads.iter().any(|gamepad| {
+                gamepad_buttons.just_pressed(GamepadButton::new(gamepad, action.button()))
+            })
+    };
+    let actions = FocusAction::ALL
+        .into_iter()
+        .filter(|action| just_pressed(*action))
+        .collect::<Vec<FocusAction>>();
+
+    // hand control back to the mouse or touch screen as soon as they are used
+    if let Some(drag) = game_input.drag_started() {
+        if drag.source() != GameInputDragSource::Virtual {
+            focus.active = false;
+        }
+    }
+    if !actions.is_empty() {
+        focus.active = true;
+    }
+    if !focus.active {
+        game_input_virtual.position = None;
+        game_input_virtual.pressed = false;
+        return;
+    }
+
+    let carrying = game_input.drag_from(GameInputDragSource::Virtual).is_some();
+    // products are carried to the bags once the pick up has gone through
+    if carrying && game_input_virtual.pressed && focus.row == FocusRow::Conveyor {
+        focus.row = FocusRow::Bags;
+        focus.entity = None;
+    }
+    for action in actions.iter() {
+        match action {
+            FocusAction::Up if focus.row != FocusRow::Bags => {
+                focus.row = FocusRow::Bags;
+                focus.entity = None;
+            }
+            FocusAction::Down if focus.row != FocusRow::Conveyor => {
+                focus.row = FocusRow::Conveyor;
+                focus.entity = None;
+            }
+            _ => {}
+        }
+    }
+
+    let mut targets = match focus.row {
+        FocusRow::Conveyor => product_query
+            .iter()
+            .map(|(entity, interactable)| (entity, interactable.center()))
+            .collect::<Vec<(Entity, Vec2)>>(),
+        FocusRow::Bags => bag_query
+            .iter()
+            .map(|(entity, interactable)| (entity, interactable.center()))
+            .collect::<Vec<(Entity, Vec2)>>(),
+    };
+    targets.sort_by(|(_, a), (_, b)| a.x.total_cmp(&b.x));
+
+    let current = focus
+        .entity
+        .and_then(|entity| targets.iter().position(|(target, _)| *target == entity))
+        .or_else(|| {
+            let position = focus.position;
+            targets
+                .iter()
+                .enumerate()
+                .min_by(|(_, (_, a)), (_, (_, b))| {
+                    (a.x - position.x)
+                        .abs()
+                        .total_cmp(&(b.x - position.x).abs())
+                })
+                .map(|(index, _)| index)
+        });
+    if let Some(mut index) = current {
+        for action in actions.iter() {
+            match action {
+                FocusAction::Left => index = index.saturating_sub(1),
+                FocusAction::Right => index = (index + 1).min(targets.len() - 1),
+                _ => {}
+            }
+        }
+        let (entity, position) = targets[index];
+        focus.entity = Some(entity);
+        focus.position = position;
+    } else {
+        focus.entity = None;
+    }
+
+    if actions.contains(&FocusAction::PickUp) && !game_input_virtual.pressed {
+        if focus.entity.is_some() {
+            game_input_virtual.pressed = true;
+        }
+    } else if actions.contains(&FocusAction::Drop) {
+        game_input_virtual.pressed = false;
+    }
+    // a bag can't be sent off while a product is on its way into it
+    if actions.contains(&FocusAction::Send) && focus.row == FocusRow::Bags && !carrying {
+        if let Some(entity) = focus.entity {
+            clear_events.send(ContainerClear { container: entity });
+        }
+    }
+    game_input_virtual.position = Some(focus.position);
+}
+
+fn focus_cursor_spawn(
+    mut spawn_events: EventReader<FocusCursorSpawnEvent>,
+    mut commands: Commands,
+) {
+    for _ in spawn_events.iter() {
+        commands
+            .spawn(SpriteBundle {
+                sprite: Sprite {
+                    custom_size: Some(FOCUS_CONVEYOR_SIZE),
+                    color: FOCUS_COLOR,
+                    ..Default::default()
+                },
+                visibility: Visibility { is_visible: false },
+                ..Default::default()
+            })
+            .insert(Transform2::default())
+            .insert(DEPTH_FOCUS)
+            .insert(FocusCursor);
+    }
+}
+
+fn focus_cursor_update(
+    mut focus_cursor_query: Query<
+        (&mut Transform2, &mut Sprite, &mut Visibility),
+        With<FocusCursor>,
+    >,
+    focus: Res<Focus>,
+    time: Res<Time>,
+) {
+    for (mut focus_cursor_transform, mut focus_cursor_sprite, mut focus_cursor_visibility) in
+        focus_cursor_query.iter_mut()
+    {
+        let visible = focus.active && focus.entity.is_some();
+        if visible && !focus_cursor_visibility.is_visible {
+            focus_cursor_transform.translation = focus.position;
+        }
+        focus_cursor_visibility.is_visible = visible;
+        focus_cursor_transform.translation = focus_cursor_transform
+            .translation
+            .lerp(focus.position, (time.delta_seconds() * 25.).min(1.));
+        focus_cursor_sprite.custom_size = Some(match focus.row {
+            FocusRow::Conveyor => FOCUS_CONVEYOR_SIZE,
+            FocusRow::Bags => FOCUS_BAG_SIZE,
+        });
+    }
+}
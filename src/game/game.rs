@@ -1,48 +1,109 @@
 use bevy::prelude::*;
 use lerp::Lerp;
-use rand::{seq::IteratorRandom, thread_rng};
-use strum::IntoEnumIterator;
+use rand::Rng;
 
-use crate::{common::Transform2, AppState, AssetLibrary};
+use crate::{
+    common::{GameRng, Transform2},
+    AppState, AssetLibrary,
+};
 
 use super::{
-    BagPlugin, BagSpawnEvent, BagSystem, ContainerPlugin, ConveyorPlugin, CustomerPlugin,
-    CustomerSpawnEvent, DeskPlugin, DeskSpawnEvent, HealthIconSpawnEvent, HealthPlugin,
-    ProductKind, ProductPlugin, ProductSpawnEvent, DEPTH_BACKGROUND, DEPTH_BACKGROUND_FRONT,
+    customer_choose_order, Bag, BagCatalog, BagPlugin, BagSpawnEvent, BagSystem, Container,
+    ContainerPlugin, Conveyor, ConveyorPlugin, CustomerPlugin, CustomerSpawnEvent, DeskPlugin,
+    DeskSpawnEvent, Difficulty, DifficultyPlugin, DifficultySystem, FocusCursorSpawnEvent,
+    FocusPlugin, HealthIconSpawnEvent, HealthPlugin, OrderQueue, ProductCatalog, ProductPlugin,
+    ProductSpawnEvent, RecordsPlugin, RuleSet, ScoreHudSpawnEvent, ScorePlugin, ShiftStatsPlugin,
+    DEPTH_BACKGROUND, DEPTH_BACKGROUND_FRONT,
 };
 
+const GAME_BAG_POSITIONS: [Vec2; 3] = [
+    Vec2::new(-700., -253.),
+    Vec2::new(-160., -253.),
+    Vec2::new(387., -253.),
+];
+
+/// Countdowns until the next customer and product, and which source belt is next in line.
+/// Reset as every shift starts, so a shift replays the same from its seed.
+#[derive(Resource)]
+pub struct GameSpawner {
+    /// Whether customers and products are spawned at all.
+    pub enabled: bool,
+    customer_time: f32,
+    product_time: f32,
+    conveyor_index: usize,
+}
+
+impl Default for GameSpawner {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            customer_time: 0.,
+            product_time: 0.,
+            conveyor_index: 0,
+        }
+    }
+}
+
+impl GameSpawner {
+    pub fn reset(&mut self) {
+        *self = Self {
+            enabled: self.enabled,
+            ..Default::default()
+        };
+    }
+}
+
 pub struct GameStatePlugin;
 
 impl Plugin for GameStatePlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugin(DeskPlugin)
+        app.init_resource::<GameSpawner>()
+            .add_plugin(DeskPlugin)
             .add_plugin(ConveyorPlugin)
             .add_plugin(BagPlugin)
             .add_plugin(CustomerPlugin)
             .add_plugin(ProductPlugin)
             .add_plugin(ContainerPlugin)
             .add_plugin(HealthPlugin)
+            .add_plugin(ShiftStatsPlugin)
+            .add_plugin(ScorePlugin)
+            .add_plugin(RecordsPlugin)
+            .add_plugin(DifficultyPlugin)
+            .add_plugin(FocusPlugin)
             .add_system_set(
                 SystemSet::on_enter(AppState::Game)
                     .with_system(game_enter)
                     .before(BagSystem::Spawn),
             )
-            .add_system_set(SystemSet::on_update(AppState::Game).with_system(game_spawn_customers))
-            .add_system_set(SystemSet::on_update(AppState::Game).with_system(game_spawn_products))
-            .add_system_set(SystemSet::on_update(AppState::Game).with_system(game_esc_to_menu));
+            .add_system_set(
+                SystemSet::on_update(AppState::Game)
+                    .with_system(game_spawn_customers.after(DifficultySystem::Update)),
+            )
+            .add_system_set(
+                SystemSet::on_update(AppState::Game)
+                    .with_system(game_spawn_products.after(DifficultySystem::Update)),
+            )
+            .add_system_set(SystemSet::on_update(AppState::Game).with_system(game_esc_to_pause));
 
         #[cfg(not(feature = "dev"))]
         app.add_plugin(super::AmbiencePlugin);
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn game_enter(
     mut commands: Commands,
     mut desk_spawn_events: EventWriter<DeskSpawnEvent>,
     mut bag_spawn_events: EventWriter<BagSpawnEvent>,
     mut health_spawn_events: EventWriter<HealthIconSpawnEvent>,
+    mut score_hud_spawn_events: EventWriter<ScoreHudSpawnEvent>,
+    mut focus_cursor_spawn_events: EventWriter<FocusCursorSpawnEvent>,
+    mut game_spawner: ResMut<GameSpawner>,
     asset_library: Res<AssetLibrary>,
+    bag_catalog: Res<BagCatalog>,
 ) {
+    game_spawner.reset();
+
     commands.spawn(Camera2dBundle::default());
 
     commands
@@ -62,18 +123,18 @@ fn game_enter(
 
     desk_spawn_events.send_default();
 
-    bag_spawn_events.send(BagSpawnEvent {
-        position: Vec2::new(-700., -253.),
-        ..Default::default()
-    });
-    bag_spawn_events.send(BagSpawnEvent {
-        position: Vec2::new(-160., -253.),
-        ..Default::default()
-    });
-    bag_spawn_events.send(BagSpawnEvent {
-        position: Vec2::new(387., -253.),
-        ..Default::default()
-    });
+    if bag_catalog.lineup.len() > GAME_BAG_POSITIONS.len() {
+        warn!(
+            "Only the first {} bags of the lineup fit on the desk",
+            GAME_BAG_POSITIONS.len()
+        );
+    }
+    for (position, id) in GAME_BAG_POSITIONS.iter().zip(bag_catalog.lineup.iter()) {
+        bag_spawn_events.send(BagSpawnEvent {
+            position: *position,
+            id: id.clone(),
+        });
+    }
 
     for i in 0..4 {
         let x = 870. - i as f32 * 90.;
@@ -82,66 +143,115 @@ fn game_enter(
             threshold: i + 1,
         });
     }
+    score_hud_spawn_events.send(ScoreHudSpawnEvent {
+        position: Vec2::new(540., 450.),
+    });
+    focus_cursor_spawn_events.send_default();
 }
 
-#[derive(Default)]
-struct GameSpawnCustomersLocal {
-    spawn_time: f32,
-}
-
+#[allow(clippy::too_many_arguments)]
 fn game_spawn_customers(
     mut customer_spawn_events: EventWriter<CustomerSpawnEvent>,
-    mut local: Local<GameSpawnCustomersLocal>,
+    mut game_spawner: ResMut<GameSpawner>,
+    mut game_rng: ResMut<GameRng>,
+    bag_query: Query<(Entity, &Bag, &Container, &Transform2)>,
+    difficulty: Res<Difficulty>,
+    rule_set: Res<RuleSet>,
+    product_catalog: Res<ProductCatalog>,
     time: Res<Time>,
 ) {
-    local.spawn_time -= time.delta_seconds();
-    if local.spawn_time <= 0. {
-        if rand::random() {
+    if !game_spawner.enabled {
+        return;
+    }
+    game_spawner.customer_time -= time.delta_seconds();
+    if game_spawner.customer_time <= 0. {
+        // whether a customer orders shapes the shift, so it comes from the gameplay stream
+        if game_rng.gameplay().gen() {
+            let rng = game_rng.cosmetic();
             customer_spawn_events.send(CustomerSpawnEvent {
-                position: Vec2::new(-1100., 100.0_f32.lerp(200., rand::random::<f32>())),
+                position: Vec2::new(-1100., 100.0_f32.lerp(200., rng.gen::<f32>())),
                 scale: 0.7,
                 speed: 100.,
                 silhouette: true,
                 ..Default::default()
             });
         } else {
+            // only customers in front of the desk place orders, made to fit the bag they get
+            let free_bag = bag_query
+                .iter()
+                .filter(|(_, bag, _, _)| bag.customer.is_none())
+                .min_by(|(_, _, _, a), (_, _, _, b)| a.translation.x.total_cmp(&b.translation.x));
+            let (bag, order) = if let Some((bag_entity, _, container, _)) = free_bag {
+                let order = customer_choose_order(
+                    difficulty.as_ref(),
+                    rule_set.as_ref(),
+                    product_catalog.as_ref(),
+                    container,
+                    game_rng.gameplay(),
+                );
+                (Some(bag_entity), order)
+            } else {
+                (None, vec![])
+            };
+            let rng = game_rng.cosmetic();
             customer_spawn_events.send(CustomerSpawnEvent {
-                position: Vec2::new(-1100., -125.0_f32.lerp(300., rand::random::<f32>())),
+                position: Vec2::new(-1100., -125.0_f32.lerp(300., rng.gen::<f32>())),
                 scale: 1.,
                 speed: 150.,
                 silhouette: false,
-                ..Default::default()
+                order,
+                bag,
             });
         }
-        local.spawn_time = 17.;
+        game_spawner.customer_time = difficulty.customer_interval;
     }
 }
 
-#[derive(Default)]
-struct GameSpawnProductsLocal {
-    spawn_time: f32,
-}
-
+#[allow(clippy::too_many_arguments)]
 fn game_spawn_products(
     mut product_spawn_events: EventWriter<ProductSpawnEvent>,
-    mut local: Local<GameSpawnProductsLocal>,
+    mut game_spawner: ResMut<GameSpawner>,
     mut commands: Commands,
+    mut game_rng: ResMut<GameRng>,
+    mut order_queue: ResMut<OrderQueue>,
+    conveyor_query: Query<(Entity, &Conveyor)>,
+    difficulty: Res<Difficulty>,
+    product_catalog: Res<ProductCatalog>,
     time: Res<Time>,
 ) {
-    local.spawn_time -= time.delta_seconds();
-    if local.spawn_time <= 0. {
-        product_spawn_events.send(ProductSpawnEvent {
-            entity: commands.spawn_empty().id(),
-            position: Vec2::new(-2000., -100.),
-            kind: ProductKind::iter().choose(&mut thread_rng()).unwrap(),
-        });
-        local.spawn_time = 1.5;
+    if !game_spawner.enabled {
+        return;
+    }
+    game_spawner.product_time -= time.delta_seconds();
+    if game_spawner.product_time <= 0. {
+        // source belts take turns, and the first product waits for the desk's belt to be ready
+        let conveyors = conveyor_query
+            .iter()
+            .filter(|(_, conveyor)| conveyor.source)
+            .map(|(entity, _)| entity)
+            .collect::<Vec<Entity>>();
+        if !conveyors.is_empty() {
+            let conveyor = conveyors[game_spawner.conveyor_index % conveyors.len()];
+            game_spawner.conveyor_index = (game_spawner.conveyor_index + 1) % conveyors.len();
+            // ordered products come first, anything else is filler
+            if let Some(kind) = order_queue.pop().or_else(|| {
+                difficulty.choose_product(product_catalog.as_ref(), game_rng.gameplay())
+            }) {
+                product_spawn_events.send(ProductSpawnEvent {
+                    entity: commands.spawn_empty().id(),
+                    position: Vec2::new(-2000., -100.),
+                    kind,
+                    conveyor,
+                });
+            }
+            game_spawner.product_time = difficulty.spawn_interval;
+        }
     }
 }
 
-fn game_esc_to_menu(mut app_state: ResMut<State<AppState>>, mut input: ResMut<Input<KeyCode>>) {
+fn game_esc_to_pause(mut app_state: ResMut<State<AppState>>, mut input: ResMut<Input<KeyCode>>) {
     if input.just_pressed(KeyCode::Escape) {
-        let _ = app_state.set(AppState::Menu);
+        let _ = app_state.push(AppState::Paused);
         input.reset(KeyCode::Escape);
     }
 }
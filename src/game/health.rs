@@ -1,19 +1,22 @@
 use bevy::prelude::*;
-use bevy_kira_audio::{Audio, AudioControl};
+use bevy_kira_audio::{AudioChannel, AudioControl};
 use bevy_spine::prelude::*;
 
 use crate::{
-    common::{SpineSync2, Transform2},
-    AssetLibrary,
+    common::{SfxChannel, SpineSync2, Transform2},
+    AppState, AssetLibrary,
 };
 
 use super::DEPTH_HEALTH;
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
 pub enum HealthSystem {
+    Reset,
     Damage,
     IconSpawn,
     IconUpdate,
+    IconLost,
+    GameOver,
 }
 
 pub struct HealthPlugin;
@@ -23,9 +26,30 @@ impl Plugin for HealthPlugin {
         app.init_resource::<Health>()
             .add_event::<HealthDamageEvent>()
             .add_event::<HealthIconSpawnEvent>()
+            .add_system_set(
+                SystemSet::on_enter(AppState::Game)
+                    .with_system(health_reset.label(HealthSystem::Reset)),
+            )
             .add_system(health_damage.label(HealthSystem::Damage))
             .add_system(health_icon_spawn.label(HealthSystem::IconSpawn))
-            .add_system(health_icon_update.label(HealthSystem::IconUpdate));
+            .add_system(
+                health_icon_update
+                    .label(HealthSystem::IconUpdate)
+                    .after(HealthSystem::Damage),
+            )
+            .add_system(
+                health_icon_lost
+                    .label(HealthSystem::IconLost)
+                    .after(SpineSystem::Update),
+            )
+            .add_system_set(
+                SystemSet::on_update(AppState::Game).with_system(
+                    health_game_over
+                        .label(HealthSystem::GameOver)
+                        .after(HealthSystem::IconUpdate)
+                        .after(HealthSystem::IconLost),
+                ),
+            );
     }
 }
 
@@ -53,6 +77,12 @@ pub struct HealthIconSpawnEvent {
 pub struct HealthIcon {
     pub threshold: u8,
     pub lost: bool,
+    /// Set once the `lose` animation has played out.
+    pub faded: bool,
+}
+
+fn health_reset(mut health: ResMut<Health>) {
+    *health = Health::default();
 }
 
 fn health_damage(
@@ -60,19 +90,17 @@ fn health_damage(
     mut health: ResMut<Health>,
     input: Res<Input<KeyCode>>,
     asset_library: Res<AssetLibrary>,
-    audio: Res<Audio>,
+    sfx_channel: Res<AudioChannel<SfxChannel>>,
 ) {
     for _ in damage_events.iter() {
         if health.amount > 0 {
-            audio.play(asset_library.audio.bag_clear_error.clone());
+            sfx_channel.play(asset_library.audio.bag_clear_error.clone());
             health.amount -= 1;
         }
     }
-    if input.just_pressed(KeyCode::H) {
-        if health.amount > 0 {
-            audio.play(asset_library.audio.bag_clear_error.clone());
-            health.amount -= 1;
-        }
+    if input.just_pressed(KeyCode::H) && health.amount > 0 {
+        sfx_channel.play(asset_library.audio.bag_clear_error.clone());
+        health.amount -= 1;
     }
 }
 
@@ -93,6 +121,7 @@ fn health_icon_spawn(
             .insert(HealthIcon {
                 threshold: event.threshold,
                 lost: false,
+                faded: false,
             });
     }
 }
@@ -107,3 +136,32 @@ fn health_icon_update(mut health_query: Query<(&mut HealthIcon, &mut Spine)>, he
         }
     }
 }
+
+fn health_icon_lost(
+    mut spine_events: EventReader<SpineEvent>,
+    mut health_query: Query<&mut HealthIcon>,
+) {
+    for event in spine_events.iter() {
+        if let SpineEvent::Complete { entity, animation } = event {
+            if let Ok(mut health_icon) = health_query.get_mut(*entity) {
+                if health_icon.lost && animation == "lose" {
+                    health_icon.faded = true;
+                }
+            }
+        }
+    }
+}
+
+// the shift only ends once the last icon has finished fading, so the player sees it go
+fn health_game_over(
+    mut app_state: ResMut<State<AppState>>,
+    health_query: Query<&HealthIcon>,
+    health: Res<Health>,
+) {
+    if health.amount == 0
+        && !health_query.is_empty()
+        && health_query.iter().all(|health_icon| health_icon.faded)
+    {
+        let _ = app_state.push(AppState::GameOver);
+    }
+}
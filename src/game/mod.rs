@@ -1,23 +1,36 @@
 mod ambience;
 mod bag;
+mod bag_catalog;
 mod container;
 mod conveyor;
 mod customer;
 mod depths;
 mod desk;
+mod difficulty;
+mod focus;
+#[allow(clippy::module_inception)]
 mod game;
 mod health;
 mod product;
 mod products;
+mod records;
+mod score;
+mod stats;
 
 pub use ambience::*;
 pub use bag::*;
+pub use bag_catalog::*;
 pub use container::*;
 pub use conveyor::*;
 pub use customer::*;
 pub use depths::*;
 pub use desk::*;
+pub use difficulty::*;
+pub use focus::*;
 pub use game::*;
 pub use health::*;
 pub use product::*;
 pub use products::*;
+pub use records::*;
+pub use score::*;
+pub use stats::*;
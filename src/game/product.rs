@@ -1,15 +1,18 @@
 use bevy::prelude::*;
+use bevy_spine::prelude::*;
 
 use crate::{
     common::{
-        CollisionShape, DepthLayer, GameInput, Interactable, SecondOrderController, Transform2,
+        CollisionShape, DepthLayer, GameInput, Interactable, SecondOrderController, SpineSync2,
+        Transform2,
     },
-    AssetLibrary,
+    AppState, AssetLibrary,
 };
 
 use super::{
     Container, ContainerInsert, ContainerInserted, ContainerSystem, ConveyorItem, ConveyorSystem,
-    ProductKind, ProductPlugins, DEPTH_PRODUCT, DEPTH_PRODUCT_DRAGGING, DEPTH_PRODUCT_ICON,
+    ProductCatalog, ProductKind, ProductPlugins, DEPTH_PRODUCT, DEPTH_PRODUCT_DRAGGING,
+    DEPTH_PRODUCT_ICON,
 };
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
@@ -35,7 +38,10 @@ impl Plugin for ProductPlugin {
                     .after(ConveyorSystem::ItemUpdate)
                     .before(ProductSystem::Inserted),
             )
-            .add_system(product_drag.label(ProductSystem::Drag))
+            .add_system_set(
+                SystemSet::on_update(AppState::Game)
+                    .with_system(product_drag.label(ProductSystem::Drag)),
+            )
             .add_system(
                 product_drop
                     .label(ProductSystem::Drop)
@@ -58,6 +64,8 @@ pub struct ProductSpawnEvent {
     pub entity: Entity,
     pub position: Vec2,
     pub kind: ProductKind,
+    /// The belt the product is put on.
+    pub conveyor: Entity,
 }
 
 #[derive(Component)]
@@ -85,6 +93,7 @@ pub struct ProductDrag(pub u64);
 fn product_spawn(
     mut spawn_events: EventReader<ProductSpawnEvent>,
     mut commands: Commands,
+    product_catalog: Res<ProductCatalog>,
     asset_library: Res<AssetLibrary>,
 ) {
     for event in spawn_events.iter() {
@@ -93,7 +102,7 @@ fn product_spawn(
             .insert(Transform2::from_translation(event.position))
             .insert(DEPTH_PRODUCT)
             .insert(Product::new(event.kind))
-            .insert(ConveyorItem::default())
+            .insert(ConveyorItem::new(event.conveyor))
             .insert(Interactable::new(
                 CollisionShape::Aabb {
                     half_extents: Vec2::splat(80.),
@@ -101,7 +110,7 @@ fn product_spawn(
                 Vec2::ZERO,
             ))
             .with_children(|parent| {
-                let attributes = event.kind.attributes();
+                let attributes = product_catalog.attributes(event.kind);
 
                 let mut y_offset = 0.;
                 for attribute in attributes.enums() {
@@ -117,9 +126,24 @@ fn product_spawn(
                     y_offset += 40.;
                 }
             });
+        if let Some(definition) = product_catalog.get(event.kind) {
+            commands
+                .entity(event.entity)
+                .insert(SpineBundle {
+                    skeleton: definition.skeleton.clone(),
+                    ..Default::default()
+                })
+                .insert(SpineSync2);
+            if let Some(rig_definition) = product_catalog.rig(event.kind) {
+                commands.entity(event.entity).insert(rig_definition.clone());
+            }
+        } else {
+            warn!("Spawned product {:?} is not in the catalog", event.kind);
+        }
     }
 }
 
+#[allow(clippy::type_complexity)]
 fn product_update(
     mut product_query: Query<(
         &mut Product,
@@ -143,9 +167,7 @@ fn product_update(
     ) in product_query.iter_mut()
     {
         let parent_translation = if let Some(product_parent) = product_parent {
-            if let Some(product_parent_transform) =
-                global_transform_query.get(product_parent.get()).ok()
-            {
+            if let Ok(product_parent_transform) = global_transform_query.get(product_parent.get()) {
                 product_parent_transform.translation().truncate()
             } else {
                 Vec2::ZERO
@@ -187,6 +209,7 @@ fn product_update(
     }
 }
 
+#[allow(clippy::type_complexity)]
 fn product_drag(
     mut commands: Commands,
     product_query: Query<
@@ -206,26 +229,58 @@ fn product_drag(
 fn product_drop(
     mut commands: Commands,
     mut attach_events: EventWriter<ContainerInsert>,
-    product_drag_query: Query<(Entity, &ProductDrag)>,
+    product_drag_query: Query<(Entity, &ProductDrag, &Interactable)>,
     container_query: Query<(Entity, &Interactable), With<Container>>,
     game_input: Res<GameInput>,
 ) {
-    for (product_drag_entity, product_drag) in product_drag_query.iter() {
+    for (product_drag_entity, product_drag, product_interactable) in product_drag_query.iter() {
         if game_input.drag_ended(product_drag.0) {
-            for (container_entity, container_interactable) in container_query.iter() {
-                if container_interactable.dragging_within(game_input.as_ref(), product_drag.0) {
-                    attach_events.send(ContainerInsert {
-                        container: container_entity,
-                        product: product_drag_entity,
-                    });
-                    break;
-                }
+            if let Some(container_entity) =
+                game_input
+                    .drag_position(product_drag.0)
+                    .and_then(|drag_position| {
+                        product_drop_target(
+                            product_interactable,
+                            drag_position,
+                            container_query.iter(),
+                        )
+                    })
+            {
+                attach_events.send(ContainerInsert {
+                    container: container_entity,
+                    product: product_drag_entity,
+                });
             }
             commands.entity(product_drag_entity).remove::<ProductDrag>();
         }
     }
 }
 
+// prefers the container under the drag, then the closest one the product's footprint overlaps
+fn product_drop_target<'a>(
+    product_interactable: &Interactable,
+    drag_position: Vec2,
+    containers: impl Iterator<Item = (Entity, &'a Interactable)>,
+) -> Option<Entity> {
+    let mut target = None;
+    let mut target_distance = f32::MAX;
+    for (container_entity, container_interactable) in containers {
+        if container_interactable.contains_point(drag_position) {
+            return Some(container_entity);
+        }
+        if container_interactable.colliding(product_interactable) {
+            let distance = container_interactable
+                .center()
+                .distance_squared(drag_position);
+            if distance < target_distance {
+                target = Some(container_entity);
+                target_distance = distance;
+            }
+        }
+    }
+    target
+}
+
 fn product_inserted(
     mut inserted_events: EventReader<ContainerInserted>,
     mut commands: Commands,
@@ -233,28 +288,37 @@ fn product_inserted(
 ) {
     for event in inserted_events.iter() {
         commands.entity(event.product).remove::<ConveyorItem>();
-        if let Some(mut product_transform) = transform_query.get_mut(event.product).ok() {
+        if let Ok(mut product_transform) = transform_query.get_mut(event.product) {
             product_transform.translation = Vec2::ZERO;
         }
     }
 }
 
 fn product_drop_candidates(
-    mut container_query: Query<(&mut Container, &Interactable)>,
-    product_drag_query: Query<(Entity, &Product, &ProductDrag)>,
+    mut container_query: Query<(Entity, &mut Container, &Interactable)>,
+    product_drag_query: Query<(Entity, &Product, &ProductDrag, &Interactable)>,
     game_input: Res<GameInput>,
 ) {
-    for (mut container, container_interactable) in container_query.iter_mut() {
+    for (_, mut container, _) in container_query.iter_mut() {
         container.drop_candidates = vec![];
-        for (product_entity, product, product_drag) in product_drag_query.iter() {
-            if container_interactable.dragging_within(game_input.as_ref(), product_drag.0) {
-                if !container.slots.iter().any(|slot| {
-                    slot.product_entity
-                        .map(|entity| entity == product_entity)
-                        .unwrap_or(false)
-                }) {
-                    container.drop_candidates.push(product.kind());
-                }
+    }
+    for (product_entity, product, product_drag, product_interactable) in product_drag_query.iter() {
+        let target = game_input
+            .drag_position(product_drag.0)
+            .and_then(|drag_position| {
+                product_drop_target(
+                    product_interactable,
+                    drag_position,
+                    container_query
+                        .iter()
+                        .map(|(entity, _, interactable)| (entity, interactable)),
+                )
+            });
+        if let Some((_, mut container, _)) =
+            target.and_then(|target| container_query.get_mut(target).ok())
+        {
+            if !container.contains(product_entity) {
+                container.drop_candidates.push(product.kind());
             }
         }
     }
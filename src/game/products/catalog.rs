@@ -0,0 +1,199 @@
+use bevy::{prelude::*, reflect::TypeUuid, utils::HashMap};
+use bevy_spine::prelude::*;
+use serde::Deserialize;
+
+use crate::{
+    common::{JiggleRigDefinition, RonAssetPlugin},
+    AssetLibrary,
+};
+
+use super::{ProductAttribute, ProductAttributes, ProductKind};
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub enum ProductCatalogSystem {
+    Load,
+}
+
+pub struct ProductCatalogPlugin;
+
+impl Plugin for ProductCatalogPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(RonAssetPlugin::<ProductCatalog>::new(&["catalog.ron"]))
+            .init_resource::<ProductCatalog>()
+            .add_system(product_catalog_load.label(ProductCatalogSystem::Load));
+    }
+}
+
+/// Every product that can show up on the conveyor, in the order they are listed in
+/// `data/products.catalog.ron`.
+#[derive(Debug, Clone, Default, Deserialize, TypeUuid, Resource)]
+#[uuid = "0b0f5c0e-4a51-4a3b-9c39-2f0b6f4f8d21"]
+pub struct ProductCatalog {
+    /// Jiggle rigs that products can refer to by name.
+    #[serde(default)]
+    pub rigs: HashMap<String, JiggleRigDefinition>,
+    pub products: Vec<ProductDefinition>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProductDefinition {
+    pub id: ProductKind,
+    pub attributes: Vec<ProductAttribute>,
+    /// Directory containing `skeleton.json` and `skeleton.atlas`.
+    pub spine: String,
+    /// Name of the jiggle rig in [`ProductCatalog::rigs`].
+    #[serde(default = "product_definition_default_rig")]
+    pub rig: String,
+    /// Loaded from `spine` once the catalog is made active.
+    #[serde(skip)]
+    pub skeleton: Handle<SkeletonData>,
+}
+
+fn product_definition_default_rig() -> String {
+    "default".to_string()
+}
+
+impl ProductCatalog {
+    /// The catalog shipped with the game, for running without an asset server.
+    pub fn builtin() -> Self {
+        ron::de::from_str(include_str!("../../../assets/data/products.catalog.ron")).unwrap()
+    }
+
+    pub fn kinds(&self) -> impl Iterator<Item = ProductKind> + '_ {
+        self.products.iter().map(|definition| definition.id)
+    }
+
+    pub fn get(&self, kind: ProductKind) -> Option<&ProductDefinition> {
+        self.products
+            .iter()
+            .find(|definition| definition.id == kind)
+    }
+
+    pub fn rig(&self, kind: ProductKind) -> Option<&JiggleRigDefinition> {
+        self.get(kind)
+            .and_then(|definition| self.rigs.get(&definition.rig))
+    }
+
+    /// Attributes of `kind`, or none if it is not in the catalog.
+    pub fn attributes(&self, kind: ProductKind) -> ProductAttributes {
+        self.get(kind)
+            .map(|definition| {
+                definition
+                    .attributes
+                    .iter()
+                    .fold(ProductAttributes::none(), |attributes, attribute| {
+                        attributes | attribute.bitwise()
+                    })
+            })
+            .unwrap_or(ProductAttributes::none())
+    }
+
+    /// Checks for products that are missing, listed twice or refer to rigs that do not exist.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.products.is_empty() {
+            return Err("no products are listed".to_string());
+        }
+        for (i, definition) in self.products.iter().enumerate() {
+            if self.products[..i]
+                .iter()
+                .any(|other_definition| other_definition.id == definition.id)
+            {
+                return Err(format!("{:?} is listed more than once", definition.id));
+            }
+            if !self.rigs.contains_key(&definition.rig) {
+                return Err(format!(
+                    "{:?} uses the rig {:?}, which is not listed",
+                    definition.id, definition.rig
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Weak handles to the skeleton files of every product, for tracking load progress.
+    pub fn handles(&self) -> Vec<HandleUntyped> {
+        self.products
+            .iter()
+            .flat_map(|definition| {
+                [
+                    HandleUntyped::weak(format!("{}/skeleton.json", definition.spine).into()),
+                    HandleUntyped::weak(format!("{}/skeleton.atlas", definition.spine).into()),
+                ]
+            })
+            .collect()
+    }
+
+    /// Weak handles to the skeleton of every product, made once the catalog is active.
+    pub fn skeletons(&self) -> Vec<Handle<SkeletonData>> {
+        self.products
+            .iter()
+            .map(|definition| definition.skeleton.clone_weak())
+            .collect()
+    }
+}
+
+fn product_catalog_load(
+    mut asset_events: EventReader<AssetEvent<ProductCatalog>>,
+    mut product_catalog: ResMut<ProductCatalog>,
+    mut skeletons: ResMut<Assets<SkeletonData>>,
+    product_catalogs: Res<Assets<ProductCatalog>>,
+    asset_server: Res<AssetServer>,
+    asset_library: Res<AssetLibrary>,
+) {
+    for event in asset_events.iter() {
+        match event {
+            AssetEvent::Created { handle } | AssetEvent::Modified { handle } => {
+                if *handle == asset_library.data.products {
+                    if let Some(loaded_catalog) = product_catalogs.get(handle) {
+                        if let Err(error) = loaded_catalog.validate() {
+                            error!("Ignoring invalid product catalog: {}", error);
+                            continue;
+                        }
+                        *product_catalog = loaded_catalog.clone();
+                        for definition in product_catalog.products.iter_mut() {
+                            definition.skeleton = skeletons.add(SkeletonData::new_from_json(
+                                asset_server.load(format!("{}/skeleton.json", definition.spine)),
+                                asset_server.load(format!("{}/skeleton.atlas", definition.spine)),
+                            ));
+                        }
+                    }
+                }
+            }
+            AssetEvent::Removed { .. } => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::ProductCatalog;
+
+    #[test]
+    fn rigs() {
+        let catalog = ProductCatalog::builtin();
+        for kind in catalog.kinds() {
+            assert!(catalog.rig(kind).is_some(), "{:?} has no rig", kind);
+        }
+    }
+
+    #[test]
+    fn builtin_is_valid() {
+        assert_eq!(ProductCatalog::builtin().validate(), Ok(()));
+    }
+
+    #[test]
+    fn invalid() {
+        let mut catalog = ProductCatalog::builtin();
+        catalog.products.clear();
+        assert!(catalog.validate().is_err());
+
+        let mut catalog = ProductCatalog::builtin();
+        let duplicate = catalog.products[0].clone();
+        catalog.products.push(duplicate);
+        assert!(catalog.validate().is_err());
+
+        let mut catalog = ProductCatalog::builtin();
+        catalog.products[0].rig = "missing".to_string();
+        assert!(catalog.validate().is_err());
+    }
+}
@@ -1,69 +1,9 @@
-mod ak47;
-mod antifreeze;
-mod avocado;
-mod bacon;
-mod batteries;
-mod beans;
-mod beer;
-mod bleach;
-mod boiling_water;
-mod bowling_ball;
-mod bread;
-mod chicken;
-mod cinder;
-mod cocaine;
-mod coffee;
-mod eggs;
-mod good_stuff;
-mod heart;
-mod ice;
-mod ice_cream;
-mod jerky;
-mod katana;
-mod ketchup;
+mod catalog;
 mod kind;
-mod milk;
-mod plate;
 mod plugins;
-mod rocket_fuel;
-mod skull;
-mod soup;
-mod taco;
-mod torch;
-mod watermelon;
-mod xxx;
+mod rules;
 
-pub use ak47::*;
-pub use antifreeze::*;
-pub use avocado::*;
-pub use bacon::*;
-pub use batteries::*;
-pub use beans::*;
-pub use beer::*;
-pub use bleach::*;
-pub use boiling_water::*;
-pub use bowling_ball::*;
-pub use bread::*;
-pub use chicken::*;
-pub use cinder::*;
-pub use cocaine::*;
-pub use coffee::*;
-pub use eggs::*;
-pub use good_stuff::*;
-pub use heart::*;
-pub use ice::*;
-pub use ice_cream::*;
-pub use jerky::*;
-pub use katana::*;
-pub use ketchup::*;
+pub use catalog::*;
 pub use kind::*;
-pub use milk::*;
-pub use plate::*;
 pub use plugins::*;
-pub use rocket_fuel::*;
-pub use skull::*;
-pub use soup::*;
-pub use taco::*;
-pub use torch::*;
-pub use watermelon::*;
-pub use xxx::*;
+pub use rules::*;
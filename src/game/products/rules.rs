@@ -0,0 +1,380 @@
+use bevy::{prelude::*, reflect::TypeUuid};
+use serde::Deserialize;
+
+use crate::common::{launch_option, RonAssetPlugin};
+
+use super::{ProductAttribute, ProductAttributes, ProductCatalog, ProductKind, ProductWeight};
+
+const RULES_ARG: &str = "--rules";
+const RULES_ENV_VAR: &str = "BAGGER_RULES";
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub enum RuleSetSystem {
+    VariantLoad,
+    Load,
+}
+
+pub struct RuleSetPlugin;
+
+impl Plugin for RuleSetPlugin {
+    fn build(&self, app: &mut App) {
+        let variant = launch_option(RULES_ARG, RULES_ENV_VAR)
+            .map(|name| RuleSetVariant::new(&name))
+            .unwrap_or_default();
+        info!("Rule set variant: {}", variant.name());
+        app.add_plugin(RonAssetPlugin::<RuleSet>::new(&["rules.ron"]))
+            .init_resource::<RuleSet>()
+            .insert_resource(variant)
+            .add_system(rule_set_variant_load.label(RuleSetSystem::VariantLoad))
+            .add_system(
+                rule_set_load
+                    .label(RuleSetSystem::Load)
+                    .after(RuleSetSystem::VariantLoad),
+            );
+    }
+}
+
+/// How products are judged once they are in a bag. The active rules are replaced whenever a
+/// valid rule set is loaded, so variants can be shipped as `*.rules.ron` files.
+#[derive(Debug, Clone, Deserialize, TypeUuid, Resource)]
+#[uuid = "5d3e8a0c-7f3b-4b8e-a0f6-6c2d1e9b4a57"]
+pub struct RuleSet {
+    /// Attributes that may not share a bag, in either order.
+    pub incompatible: Vec<(ProductAttribute, ProductAttribute)>,
+    /// Weight classes of attributes. A product takes the heaviest class of its attributes and is
+    /// [`ProductWeight::Normal`] if none of them are listed.
+    pub weights: Vec<(ProductAttribute, ProductWeight)>,
+    pub ordering: Vec<StackOrder>,
+}
+
+/// Which `data/<name>.rules.ron` is made active, chosen with `--rules <name>`. Insert a new one
+/// to switch rule sets.
+#[derive(Resource)]
+pub struct RuleSetVariant {
+    name: String,
+    handle: Option<Handle<RuleSet>>,
+}
+
+impl Default for RuleSetVariant {
+    fn default() -> Self {
+        Self::new("standard")
+    }
+}
+
+impl RuleSetVariant {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            handle: None,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn path(&self) -> String {
+        format!("data/{}.rules.ron", self.name)
+    }
+
+    /// Weak handle to the rule set file, for tracking load progress. Empty until the file has
+    /// been asked for.
+    pub fn handles(&self) -> Vec<HandleUntyped> {
+        self.handle
+            .iter()
+            .map(|handle| handle.clone_weak_untyped())
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum StackOrder {
+    /// Nothing may sit on top of something lighter than itself.
+    HeavierBelow,
+    /// Products with the first attribute may not sit on top of products with the second.
+    NotAbove(ProductAttribute, ProductAttribute),
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl RuleSet {
+    /// The standard rules shipped with the game, for running without an asset server.
+    pub fn builtin() -> Self {
+        ron::de::from_str(include_str!("../../../assets/data/standard.rules.ron")).unwrap()
+    }
+
+    /// Checks for rules that contradict or repeat each other.
+    pub fn validate(&self) -> Result<(), String> {
+        for (i, (attribute, other_attribute)) in self.incompatible.iter().enumerate() {
+            if self.incompatible[..i].iter().any(|pair| {
+                *pair == (*attribute, *other_attribute) || *pair == (*other_attribute, *attribute)
+            }) {
+                return Err(format!(
+                    "{:?} and {:?} are listed as incompatible more than once",
+                    attribute, other_attribute
+                ));
+            }
+        }
+        for (i, (attribute, _)) in self.weights.iter().enumerate() {
+            if self.weights[..i]
+                .iter()
+                .any(|(other_attribute, _)| other_attribute == attribute)
+            {
+                return Err(format!("{:?} is given more than one weight", attribute));
+            }
+        }
+        for (i, order) in self.ordering.iter().enumerate() {
+            if self.ordering[..i].contains(order) {
+                return Err(format!("{:?} is listed more than once", order));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn compatible(&self, attribute: ProductAttribute, other: ProductAttribute) -> bool {
+        !self
+            .incompatible
+            .iter()
+            .any(|pair| *pair == (attribute, other) || *pair == (other, attribute))
+    }
+
+    pub fn compatible_attributes(
+        &self,
+        attributes: ProductAttributes,
+        other: ProductAttributes,
+    ) -> bool {
+        for attribute in attributes.enums() {
+            for other_attribute in other.enums() {
+                if !self.compatible(attribute, other_attribute) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    pub fn attribute_weight(&self, attribute: ProductAttribute) -> Option<ProductWeight> {
+        self.weights
+            .iter()
+            .find(|(weighted_attribute, _)| *weighted_attribute == attribute)
+            .map(|(_, weight)| *weight)
+    }
+
+    pub fn weight(&self, attributes: ProductAttributes) -> ProductWeight {
+        attributes
+            .enums()
+            .filter_map(|attribute| self.attribute_weight(attribute))
+            .max()
+            .unwrap_or(ProductWeight::Normal)
+    }
+
+    /// Whether `above` may sit anywhere on top of `below`.
+    pub fn can_stack(&self, below: ProductAttributes, above: ProductAttributes) -> bool {
+        if !self.compatible_attributes(below, above) {
+            return false;
+        }
+        self.ordering.iter().all(|order| match *order {
+            StackOrder::HeavierBelow => self.weight(above) <= self.weight(below),
+            StackOrder::NotAbove(above_attribute, below_attribute) => {
+                !(above.contains(above_attribute.bitwise())
+                    && below.contains(below_attribute.bitwise()))
+            }
+        })
+    }
+
+    /// Whether `products`, listed from the bottom of the bag up, break none of the rules.
+    pub fn valid_stack(&self, product_catalog: &ProductCatalog, products: &[ProductKind]) -> bool {
+        for (i, product) in products.iter().enumerate() {
+            let attributes = product_catalog.attributes(*product);
+            for other_product in products[..i].iter() {
+                if !self.can_stack(product_catalog.attributes(*other_product), attributes) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+fn rule_set_variant_load(
+    mut rule_set_variant: ResMut<RuleSetVariant>,
+    asset_server: Res<AssetServer>,
+) {
+    if rule_set_variant.handle.is_none() {
+        rule_set_variant.handle = Some(asset_server.load(rule_set_variant.path()));
+    }
+}
+
+fn rule_set_load(
+    mut asset_events: EventReader<AssetEvent<RuleSet>>,
+    mut rule_set: ResMut<RuleSet>,
+    rule_sets: Res<Assets<RuleSet>>,
+    rule_set_variant: Res<RuleSetVariant>,
+) {
+    for event in asset_events.iter() {
+        match event {
+            AssetEvent::Created { handle } | AssetEvent::Modified { handle } => {
+                if rule_set_variant.handle.as_ref() == Some(handle) {
+                    if let Some(loaded_rule_set) = rule_sets.get(handle) {
+                        match loaded_rule_set.validate() {
+                            Ok(()) => *rule_set = loaded_rule_set.clone(),
+                            Err(error) => error!("Ignoring invalid rule set: {}", error),
+                        }
+                    }
+                }
+            }
+            AssetEvent::Removed { .. } => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::{
+        ProductAttribute, ProductAttributes, ProductCatalog, ProductKind, ProductWeight, RuleSet,
+        RuleSetVariant, StackOrder,
+    };
+
+    fn kinds(ids: &[&str]) -> Vec<ProductKind> {
+        ids.iter().map(|id| ProductKind::new(id)).collect()
+    }
+
+    fn compatible_products(rule_set: &RuleSet, kind: &str, other: &str) -> bool {
+        let catalog = ProductCatalog::builtin();
+        rule_set.compatible_attributes(
+            catalog.attributes(ProductKind::new(kind)),
+            catalog.attributes(ProductKind::new(other)),
+        )
+    }
+
+    #[test]
+    fn compatible() {
+        let rule_set = RuleSet::builtin();
+        assert!(rule_set.compatible(ProductAttribute::Cold, ProductAttribute::Cold));
+        assert!(rule_set.compatible(ProductAttribute::Hot, ProductAttribute::Hot));
+        assert!(rule_set.compatible(ProductAttribute::Hot, ProductAttribute::Toxic));
+        assert!(rule_set.compatible(ProductAttribute::Meat, ProductAttribute::Heavy));
+        assert!(rule_set.compatible(ProductAttribute::Meat, ProductAttribute::Fragile));
+
+        assert!(compatible_products(&rule_set, "jerky", "beans"));
+        assert!(compatible_products(&rule_set, "soup", "chicken"));
+    }
+
+    #[test]
+    fn incompatible() {
+        let rule_set = RuleSet::builtin();
+        assert!(!rule_set.compatible(ProductAttribute::Cold, ProductAttribute::Hot));
+        assert!(!rule_set.compatible(ProductAttribute::Hot, ProductAttribute::Cold));
+
+        assert!(!rule_set.compatible(ProductAttribute::Fresh, ProductAttribute::Meat));
+        assert!(!rule_set.compatible(ProductAttribute::Meat, ProductAttribute::Fresh));
+
+        assert!(!rule_set.compatible(ProductAttribute::Fresh, ProductAttribute::Toxic));
+        assert!(!rule_set.compatible(ProductAttribute::Toxic, ProductAttribute::Fresh));
+
+        assert!(!rule_set.compatible(ProductAttribute::Meat, ProductAttribute::Toxic));
+        assert!(!rule_set.compatible(ProductAttribute::Toxic, ProductAttribute::Meat));
+
+        assert!(!rule_set.compatible(ProductAttribute::Illicit, ProductAttribute::Illicit));
+
+        assert!(!compatible_products(&rule_set, "soup", "ice_cream"));
+        assert!(!compatible_products(&rule_set, "ak47", "katana"));
+    }
+
+    #[test]
+    fn weights() {
+        let rule_set = RuleSet::builtin();
+        let catalog = ProductCatalog::builtin();
+        let heavy_item = rule_set.weight(ProductAttributes::Heavy);
+        let normal_item = rule_set.weight(ProductAttributes::none());
+        let light_item = rule_set.weight(ProductAttributes::Fragile);
+
+        assert_eq!(heavy_item, ProductWeight::Heavy);
+        assert_eq!(normal_item, ProductWeight::Normal);
+        assert_eq!(light_item, ProductWeight::Light);
+
+        assert!(heavy_item > normal_item);
+        assert!(normal_item > light_item);
+
+        let weight = |id: &str| rule_set.weight(catalog.attributes(ProductKind::new(id)));
+        assert_eq!(weight("cinder"), ProductWeight::Heavy);
+        assert_eq!(weight("jerky"), ProductWeight::Normal);
+        assert_eq!(weight("bread"), ProductWeight::Light);
+    }
+
+    #[test]
+    fn valid_stack() {
+        let rule_set = RuleSet::builtin();
+        let catalog = ProductCatalog::builtin();
+        assert!(rule_set.valid_stack(&catalog, &[]));
+        assert!(rule_set.valid_stack(&catalog, &kinds(&["cinder", "jerky", "plate"])));
+        assert!(rule_set.valid_stack(&catalog, &kinds(&["beans", "eggs", "plate"])));
+    }
+
+    #[test]
+    fn invalid_stack() {
+        let rule_set = RuleSet::builtin();
+        let catalog = ProductCatalog::builtin();
+        assert!(!rule_set.valid_stack(&catalog, &kinds(&["jerky", "jerky", "cinder"])));
+        assert!(!rule_set.valid_stack(&catalog, &kinds(&["beans", "plate", "jerky"])));
+        assert!(!rule_set.valid_stack(&catalog, &kinds(&["soup", "ice_cream"])));
+        assert!(!rule_set.valid_stack(&catalog, &kinds(&["watermelon", "batteries"])));
+        assert!(!rule_set.valid_stack(&catalog, &kinds(&["ak47", "katana"])));
+    }
+
+    #[test]
+    fn not_above() {
+        let mut rule_set = RuleSet::builtin();
+        rule_set.ordering.push(StackOrder::NotAbove(
+            ProductAttribute::Toxic,
+            ProductAttribute::Hot,
+        ));
+        let catalog = ProductCatalog::builtin();
+        assert!(rule_set.valid_stack(&catalog, &kinds(&["bleach", "soup"])));
+        assert!(!rule_set.valid_stack(&catalog, &kinds(&["soup", "bleach"])));
+    }
+
+    #[test]
+    fn builtin_is_valid() {
+        assert_eq!(RuleSet::builtin().validate(), Ok(()));
+    }
+
+    #[test]
+    fn hazardous_variant() {
+        let rule_set: RuleSet =
+            ron::de::from_str(include_str!("../../../assets/data/hazardous.rules.ron")).unwrap();
+        assert_eq!(rule_set.validate(), Ok(()));
+        assert_eq!(
+            RuleSetVariant::new("hazardous").path(),
+            "data/hazardous.rules.ron"
+        );
+
+        let catalog = ProductCatalog::builtin();
+        assert!(RuleSet::builtin().valid_stack(&catalog, &kinds(&["bleach", "soup"])));
+        assert!(!rule_set.valid_stack(&catalog, &kinds(&["bleach", "soup"])));
+        assert!(rule_set.valid_stack(&catalog, &kinds(&["cinder", "jerky", "plate"])));
+    }
+
+    #[test]
+    fn invalid() {
+        let mut rule_set = RuleSet::builtin();
+        rule_set
+            .incompatible
+            .push((ProductAttribute::Hot, ProductAttribute::Cold));
+        assert!(rule_set.validate().is_err());
+
+        let mut rule_set = RuleSet::builtin();
+        rule_set
+            .weights
+            .push((ProductAttribute::Heavy, ProductWeight::Light));
+        assert!(rule_set.validate().is_err());
+
+        let mut rule_set = RuleSet::builtin();
+        rule_set.ordering.push(StackOrder::HeavierBelow);
+        assert!(rule_set.validate().is_err());
+    }
+}
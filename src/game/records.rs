@@ -0,0 +1,310 @@
+use std::{error::Error, fs, path::PathBuf};
+
+use bevy::{app::AppExit, prelude::*, utils::HashMap};
+use serde::{Deserialize, Serialize};
+
+use crate::{common::config_dir, AppState};
+
+use super::{BagClearedEvent, BagSystem, ProductKind, Score, ShiftStats};
+
+const RECORDS_FILE: &str = "records.ron";
+
+/// Version written to new record files. Bump it when [`Records`] changes in a way old files can't
+/// be read as, and teach `Records::migrate` to convert the previous version.
+pub const RECORDS_VERSION: u32 = 1;
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub enum RecordsSystem {
+    BagCleared,
+    ShiftEnd,
+    AppExit,
+}
+
+pub struct RecordsPlugin;
+
+impl Plugin for RecordsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Records::load())
+            .add_system(
+                records_bag_cleared
+                    .label(RecordsSystem::BagCleared)
+                    .after(BagSystem::Clear),
+            )
+            // a shift ends whether the player lost or quit from the pause menu
+            .add_system_set(
+                SystemSet::on_exit(AppState::Game)
+                    .with_system(records_shift_end.label(RecordsSystem::ShiftEnd)),
+            )
+            // runs last so it sees exits asked for anywhere in the frame
+            .add_system_to_stage(
+                CoreStage::Last,
+                records_app_exit.label(RecordsSystem::AppExit),
+            );
+    }
+}
+
+/// Bests and lifetime totals across every shift played, persisted as `records.ron` in the
+/// platform config directory.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Resource)]
+#[serde(default)]
+pub struct Records {
+    pub best_score: u32,
+    /// Longest shift in seconds.
+    pub longest_shift: f32,
+    pub shifts_played: u32,
+    pub bags_cleared: u32,
+    pub products: HashMap<ProductKind, ProductRecord>,
+    /// Where the records are saved, if anywhere.
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+}
+
+/// How many valid and ruined bags a product has been in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProductRecord {
+    pub bagged: u32,
+    pub failed: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RecordsFile<T> {
+    version: u32,
+    records: T,
+}
+
+/// Read first to find out how the rest of a records file should be parsed. Files saved before
+/// the header was added count as version 0.
+#[derive(Deserialize)]
+struct RecordsFileHeader {
+    #[serde(default)]
+    version: u32,
+}
+
+/// Records as saved by version 0, bare and without per-product counts.
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct RecordsV0 {
+    best_score: u32,
+    longest_shift: f32,
+    shifts_played: u32,
+    bags_cleared: u32,
+}
+
+impl From<RecordsV0> for Records {
+    fn from(records: RecordsV0) -> Self {
+        Self {
+            best_score: records.best_score,
+            longest_shift: records.longest_shift,
+            shifts_played: records.shifts_played,
+            bags_cleared: records.bags_cleared,
+            ..Default::default()
+        }
+    }
+}
+
+impl Records {
+    /// Reads the saved records, or starts fresh if there are none. Files that can't be read are
+    /// left alone rather than overwritten.
+    pub fn load() -> Self {
+        let path = match config_dir() {
+            Some(dir) => dir.join(RECORDS_FILE),
+            None => return Self::default(),
+        };
+        let mut records = match fs::read_to_string(&path) {
+            Ok(contents) => match Self::from_ron(&contents) {
+                Ok(records) => records,
+                Err(error) => {
+                    error!("Failed to read records {}: {}", path.display(), error);
+                    return Self::default();
+                }
+            },
+            Err(_) => Self::default(),
+        };
+        records.path = Some(path);
+        records
+    }
+
+    /// Parses a records file of any supported version.
+    pub fn from_ron(contents: &str) -> Result<Self, Box<dyn Error>> {
+        let header: RecordsFileHeader = ron::from_str(contents)?;
+        Self::migrate(header.version, contents)
+    }
+
+    pub fn to_ron(&self) -> Result<String, Box<dyn Error>> {
+        let file = RecordsFile {
+            version: RECORDS_VERSION,
+            records: self,
+        };
+        Ok(ron::ser::to_string_pretty(
+            &file,
+            ron::ser::PrettyConfig::default(),
+        )?)
+    }
+
+    /// Reads records written by this or an older version of the game. Older versions get an arm
+    /// that parses their own layout and converts it.
+    fn migrate(version: u32, contents: &str) -> Result<Self, Box<dyn Error>> {
+        match version {
+            0 => Ok(ron::from_str::<RecordsV0>(contents)?.into()),
+            RECORDS_VERSION => Ok(ron::from_str::<RecordsFile<Records>>(contents)?.records),
+            _ => Err(format!("unsupported records version {}", version).into()),
+        }
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn Error>> {
+        if let Some(path) = &self.path {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, self.to_ron()?)?;
+        }
+        Ok(())
+    }
+
+    /// Counts every product of a cleared bag towards its record.
+    pub fn record_bag(&mut self, event: &BagClearedEvent) {
+        if event.valid {
+            self.bags_cleared += 1;
+        }
+        for product in event.products.iter() {
+            let record = self.products.entry(*product).or_default();
+            if event.valid {
+                record.bagged += 1;
+            } else {
+                record.failed += 1;
+            }
+        }
+    }
+
+    pub fn record_shift(&mut self, score: &Score, shift_stats: &ShiftStats) {
+        self.shifts_played += 1;
+        self.best_score = self.best_score.max(score.points);
+        self.longest_shift = self.longest_shift.max(shift_stats.time);
+    }
+}
+
+fn records_bag_cleared(
+    mut cleared_events: EventReader<BagClearedEvent>,
+    mut records: ResMut<Records>,
+) {
+    for event in cleared_events.iter() {
+        records.record_bag(event);
+    }
+}
+
+fn records_shift_end(
+    mut records: ResMut<Records>,
+    score: Res<Score>,
+    shift_stats: Res<ShiftStats>,
+) {
+    records.record_shift(score.as_ref(), shift_stats.as_ref());
+    if let Err(error) = records.save() {
+        error!("Failed to save records: {}", error);
+    }
+}
+
+// closing the game mid-shift never leaves it, so the shift is counted here instead
+fn records_app_exit(
+    mut exit_events: EventReader<AppExit>,
+    mut records: ResMut<Records>,
+    app_state: Res<State<AppState>>,
+    score: Res<Score>,
+    shift_stats: Res<ShiftStats>,
+) {
+    if exit_events.iter().next().is_none() {
+        return;
+    }
+    if *app_state.current() == AppState::Game || app_state.inactives().contains(&AppState::Game) {
+        records.record_shift(score.as_ref(), shift_stats.as_ref());
+    }
+    if let Err(error) = records.save() {
+        error!("Failed to save records: {}", error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use crate::game::{BagClearedEvent, ProductKind, Records, Score, ShiftStats};
+
+    #[test]
+    fn round_trip() {
+        let mut records = Records::default();
+        records.record_bag(&BagClearedEvent {
+            container: Entity::from_raw(0),
+            products: vec![ProductKind::new("jerky"), ProductKind::new("beans")],
+            valid: true,
+            partial: false,
+        });
+        records.record_bag(&BagClearedEvent {
+            container: Entity::from_raw(0),
+            products: vec![ProductKind::new("jerky")],
+            valid: false,
+            partial: false,
+        });
+        records.record_shift(
+            &Score {
+                points: 1200,
+                ..Default::default()
+            },
+            &ShiftStats {
+                time: 90.,
+                ..Default::default()
+            },
+        );
+
+        let loaded = Records::from_ron(&records.to_ron().unwrap()).unwrap();
+        assert_eq!(loaded, records);
+        assert_eq!(loaded.best_score, 1200);
+        assert_eq!(loaded.bags_cleared, 1);
+        let jerky = loaded.products[&ProductKind::new("jerky")];
+        assert_eq!((jerky.bagged, jerky.failed), (1, 1));
+    }
+
+    #[test]
+    fn loads_v0() {
+        let records = Records::from_ron(
+            "(best_score: 800, longest_shift: 61.5, shifts_played: 3, bags_cleared: 12)",
+        )
+        .unwrap();
+        assert_eq!(
+            records,
+            Records {
+                best_score: 800,
+                longest_shift: 61.5,
+                shifts_played: 3,
+                bags_cleared: 12,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn loads_v1() {
+        let records = Records::from_ron(
+            r#"(
+                version: 1,
+                records: (
+                    best_score: 1200,
+                    longest_shift: 90.0,
+                    shifts_played: 4,
+                    bags_cleared: 20,
+                    products: {
+                        "jerky": (bagged: 5, failed: 1),
+                    },
+                ),
+            )"#,
+        )
+        .unwrap();
+        assert_eq!(records.best_score, 1200);
+        assert_eq!(records.shifts_played, 4);
+        let jerky = records.products[&ProductKind::new("jerky")];
+        assert_eq!((jerky.bagged, jerky.failed), (5, 1));
+    }
+
+    #[test]
+    fn unsupported_version() {
+        assert!(Records::from_ron("(version: 999, records: ())").is_err());
+    }
+}
@@ -0,0 +1,212 @@
+use bevy::prelude::*;
+
+use crate::{common::Transform2, AppState, AssetLibrary};
+
+use super::{
+    BagSystem, HealthDamageEvent, HealthSystem, ProductCatalog, ProductKind, RuleSet, DEPTH_HEALTH,
+};
+
+const SCORE_PRODUCT_POINTS: u32 = 100;
+const SCORE_ORDER_POINTS: u32 = 50;
+const SCORE_SHARED_ATTRIBUTE_POINTS: u32 = 75;
+const SCORE_MAX_MULTIPLIER: u32 = 5;
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub enum ScoreSystem {
+    Reset,
+    Damage,
+    BagCleared,
+    HudSpawn,
+    HudUpdate,
+}
+
+pub struct ScorePlugin;
+
+impl Plugin for ScorePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Score>()
+            .add_event::<BagClearedEvent>()
+            .add_event::<ScoreHudSpawnEvent>()
+            .add_system_set(
+                SystemSet::on_enter(AppState::Game)
+                    .with_system(score_reset.label(ScoreSystem::Reset)),
+            )
+            .add_system(
+                score_damage
+                    .label(ScoreSystem::Damage)
+                    .before(HealthSystem::Damage),
+            )
+            .add_system(
+                score_bag_cleared
+                    .label(ScoreSystem::BagCleared)
+                    .after(ScoreSystem::Damage)
+                    .after(BagSystem::Clear),
+            )
+            .add_system(score_hud_spawn.label(ScoreSystem::HudSpawn))
+            .add_system(
+                score_hud_update
+                    .label(ScoreSystem::HudUpdate)
+                    .after(ScoreSystem::BagCleared),
+            );
+    }
+}
+
+#[derive(Default, Resource)]
+pub struct Score {
+    pub points: u32,
+    pub combo: u32,
+    pub best_combo: u32,
+}
+
+impl Score {
+    pub fn multiplier(&self) -> u32 {
+        self.combo.clamp(1, SCORE_MAX_MULTIPLIER)
+    }
+
+    /// Points for a valid stack before the combo multiplier is applied.
+    pub fn stack_points(
+        rule_set: &RuleSet,
+        product_catalog: &ProductCatalog,
+        products: &[ProductKind],
+    ) -> u32 {
+        let mut points = products.len() as u32 * SCORE_PRODUCT_POINTS;
+        for pair in products.windows(2) {
+            if rule_set.weight(product_catalog.attributes(pair[0]))
+                > rule_set.weight(product_catalog.attributes(pair[1]))
+            {
+                points += SCORE_ORDER_POINTS;
+            }
+        }
+        if products.len() > 1 {
+            let shared = products.iter().skip(1).fold(
+                product_catalog.attributes(products[0]),
+                |shared, product| shared & product_catalog.attributes(*product),
+            );
+            points += shared.enums().count() as u32 * SCORE_SHARED_ATTRIBUTE_POINTS;
+        }
+        points
+    }
+}
+
+pub struct BagClearedEvent {
+    pub container: Entity,
+    pub products: Vec<ProductKind>,
+    pub valid: bool,
+    /// Whether the bag was sent off before every slot was filled.
+    pub partial: bool,
+}
+
+#[derive(Default)]
+pub struct ScoreHudSpawnEvent {
+    pub position: Vec2,
+}
+
+#[derive(Component)]
+pub struct ScoreHud;
+
+fn score_reset(mut score: ResMut<Score>) {
+    *score = Score::default();
+}
+
+fn score_damage(mut damage_events: EventReader<HealthDamageEvent>, mut score: ResMut<Score>) {
+    for _ in damage_events.iter() {
+        score.combo = 0;
+    }
+}
+
+fn score_bag_cleared(
+    mut cleared_events: EventReader<BagClearedEvent>,
+    mut score: ResMut<Score>,
+    rule_set: Res<RuleSet>,
+    product_catalog: Res<ProductCatalog>,
+) {
+    for event in cleared_events.iter() {
+        let points =
+            Score::stack_points(rule_set.as_ref(), product_catalog.as_ref(), &event.products);
+        if event.valid && event.partial {
+            // a short bag is worth its products, but breaks the combo
+            score.combo = 0;
+            score.points += points;
+        } else if event.valid {
+            score.combo += 1;
+            score.best_combo = score.best_combo.max(score.combo);
+            score.points += points * score.multiplier();
+        }
+    }
+}
+
+fn score_hud_spawn(
+    mut spawn_events: EventReader<ScoreHudSpawnEvent>,
+    mut commands: Commands,
+    asset_library: Res<AssetLibrary>,
+) {
+    for event in spawn_events.iter() {
+        let text_style = |color: Color| TextStyle {
+            font: asset_library.fonts.default.clone(),
+            font_size: 60.,
+            color,
+        };
+        commands
+            .spawn(Text2dBundle {
+                text: Text::from_sections([
+                    TextSection::new("0", text_style(Color::WHITE)),
+                    TextSection::new("", text_style(Color::YELLOW)),
+                ])
+                .with_alignment(TextAlignment::CENTER_RIGHT),
+                ..Default::default()
+            })
+            .insert(Transform2::from_translation(event.position))
+            .insert(DEPTH_HEALTH)
+            .insert(ScoreHud);
+    }
+}
+
+fn score_hud_update(mut score_hud_query: Query<&mut Text, With<ScoreHud>>, score: Res<Score>) {
+    for mut score_hud_text in score_hud_query.iter_mut() {
+        score_hud_text.sections[0].value = score.points.to_string();
+        score_hud_text.sections[1].value = if score.multiplier() > 1 {
+            format!(" x{}", score.multiplier())
+        } else {
+            String::new()
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::{ProductCatalog, ProductKind, RuleSet, Score};
+
+    #[test]
+    fn stack_points() {
+        let rule_set = RuleSet::builtin();
+        let catalog = ProductCatalog::builtin();
+        let kinds = |ids: &[&str]| {
+            ids.iter()
+                .map(|id| ProductKind::new(id))
+                .collect::<Vec<ProductKind>>()
+        };
+        assert_eq!(Score::stack_points(&rule_set, &catalog, &[]), 0);
+        assert_eq!(
+            Score::stack_points(&rule_set, &catalog, &kinds(&["beans"])),
+            100
+        );
+        assert_eq!(
+            Score::stack_points(&rule_set, &catalog, &kinds(&["cinder", "jerky", "plate"])),
+            400
+        );
+        assert_eq!(
+            Score::stack_points(&rule_set, &catalog, &kinds(&["bread", "eggs"])),
+            350
+        );
+    }
+
+    #[test]
+    fn multiplier() {
+        let mut score = Score::default();
+        assert_eq!(score.multiplier(), 1);
+        score.combo = 3;
+        assert_eq!(score.multiplier(), 3);
+        score.combo = 20;
+        assert_eq!(score.multiplier(), 5);
+    }
+}
@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+
+use crate::AppState;
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub enum ShiftStatsSystem {
+    Reset,
+    Update,
+}
+
+pub struct ShiftStatsPlugin;
+
+impl Plugin for ShiftStatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ShiftStats>()
+            .add_system_set(
+                SystemSet::on_enter(AppState::Game)
+                    .with_system(shift_stats_reset.label(ShiftStatsSystem::Reset)),
+            )
+            .add_system_set(
+                SystemSet::on_update(AppState::Game)
+                    .with_system(shift_stats_update.label(ShiftStatsSystem::Update)),
+            );
+    }
+}
+
+#[derive(Default, Resource)]
+pub struct ShiftStats {
+    pub time: f32,
+    pub bags_cleared: u32,
+    pub bags_failed: u32,
+    pub products_bagged: u32,
+    pub products_missed: u32,
+}
+
+fn shift_stats_reset(mut shift_stats: ResMut<ShiftStats>) {
+    *shift_stats = ShiftStats::default();
+}
+
+fn shift_stats_update(mut shift_stats: ResMut<ShiftStats>, time: Res<Time>) {
+    shift_stats.time += time.delta_seconds();
+}
@@ -0,0 +1,5 @@
+mod plugin;
+mod results;
+
+pub use plugin::*;
+pub use results::*;
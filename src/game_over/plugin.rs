@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+use crate::AppState;
+
+use super::{ResultsPlugin, ResultsSpawnEvent, ResultsSystem};
+
+pub struct GameOverStatePlugin;
+
+impl Plugin for GameOverStatePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(ResultsPlugin)
+            .add_system_set(
+                SystemSet::on_enter(AppState::GameOver)
+                    .with_system(game_over_enter)
+                    .before(ResultsSystem::Spawn),
+            )
+            .add_system_set(
+                SystemSet::on_update(AppState::GameOver).with_system(game_over_esc_to_menu),
+            );
+    }
+}
+
+fn game_over_enter(mut results_spawn_events: EventWriter<ResultsSpawnEvent>) {
+    results_spawn_events.send_default();
+}
+
+fn game_over_esc_to_menu(
+    mut app_state: ResMut<State<AppState>>,
+    mut input: ResMut<Input<KeyCode>>,
+) {
+    if input.just_pressed(KeyCode::Escape) {
+        let _ = app_state.replace(AppState::Menu);
+        input.reset(KeyCode::Escape);
+    }
+}
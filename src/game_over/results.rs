@@ -0,0 +1,182 @@
+use bevy::prelude::*;
+
+use crate::{
+    common::{CollisionShape, DepthLayer, GameInput, GameRng, Interactable, Transform2},
+    game::{Records, Score, ShiftStats, DEPTH_RESULTS},
+    AppState, AssetLibrary,
+};
+
+const RESULTS_PANEL_SIZE: Vec2 = Vec2::new(900., 700.);
+const RESULTS_BUTTON_SIZE: Vec2 = Vec2::new(300., 90.);
+const RESULTS_BUTTON_COLOR: Color = Color::rgb(0.25, 0.25, 0.25);
+const RESULTS_BUTTON_HOVER_COLOR: Color = Color::rgb(0.4, 0.4, 0.4);
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub enum ResultsSystem {
+    Spawn,
+    ButtonUpdate,
+}
+
+pub struct ResultsPlugin;
+
+impl Plugin for ResultsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ResultsSpawnEvent>()
+            .add_system(results_spawn.label(ResultsSystem::Spawn))
+            .add_system(results_button_update.label(ResultsSystem::ButtonUpdate));
+    }
+}
+
+#[derive(Default)]
+pub struct ResultsSpawnEvent;
+
+#[derive(Component)]
+pub struct Results;
+
+#[derive(Component)]
+struct ResultsButton {
+    kind: ResultsButtonKind,
+}
+
+#[derive(Clone, Copy)]
+enum ResultsButtonKind {
+    Retry,
+    Menu,
+}
+
+fn results_spawn(
+    mut spawn_events: EventReader<ResultsSpawnEvent>,
+    mut commands: Commands,
+    asset_library: Res<AssetLibrary>,
+    shift_stats: Res<ShiftStats>,
+    score: Res<Score>,
+    records: Res<Records>,
+    game_rng: Res<GameRng>,
+) {
+    for _ in spawn_events.iter() {
+        let text_style = |font_size: f32| TextStyle {
+            font: asset_library.fonts.default.clone(),
+            font_size,
+            color: Color::WHITE,
+        };
+        let minutes = shift_stats.time as u32 / 60;
+        let seconds = shift_stats.time as u32 % 60;
+        let lines = [
+            format!("Score: {}", score.points),
+            // the records may not have taken this shift into account yet
+            format!("Best score: {}", records.best_score.max(score.points)),
+            format!("Best combo: x{}", score.best_combo),
+            format!("Shift length: {}:{:02}", minutes, seconds),
+            format!("Bags cleared: {}", shift_stats.bags_cleared),
+            format!("Bags ruined: {}", shift_stats.bags_failed),
+            format!("Products bagged: {}", shift_stats.products_bagged),
+            format!("Products missed: {}", shift_stats.products_missed),
+            format!("Seed: {}", game_rng.seed()),
+        ];
+        commands
+            .spawn(SpriteBundle {
+                sprite: Sprite {
+                    custom_size: Some(Vec2::new(1920., 1080.)),
+                    color: Color::rgba(0., 0., 0., 0.6),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .insert(Transform2::default())
+            .insert(DEPTH_RESULTS)
+            .insert(Results)
+            .with_children(|parent| {
+                parent
+                    .spawn(SpriteBundle {
+                        sprite: Sprite {
+                            custom_size: Some(RESULTS_PANEL_SIZE),
+                            color: Color::rgba(0.1, 0.1, 0.1, 0.95),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    })
+                    .insert(Transform2::default())
+                    .insert(DepthLayer::Inherit(0.1));
+                parent
+                    .spawn(Text2dBundle {
+                        text: Text::from_section("Shift Over", text_style(80.))
+                            .with_alignment(TextAlignment::CENTER),
+                        ..Default::default()
+                    })
+                    .insert(Transform2::from_xy(0., 250.))
+                    .insert(DepthLayer::Inherit(0.2));
+                for (i, line) in lines.into_iter().enumerate() {
+                    parent
+                        .spawn(Text2dBundle {
+                            text: Text::from_section(line, text_style(40.))
+                                .with_alignment(TextAlignment::CENTER),
+                            ..Default::default()
+                        })
+                        .insert(Transform2::from_xy(0., 170. - i as f32 * 45.))
+                        .insert(DepthLayer::Inherit(0.2));
+                }
+                for (x, label, kind) in [
+                    (-180., "Retry", ResultsButtonKind::Retry),
+                    (180., "Menu", ResultsButtonKind::Menu),
+                ] {
+                    parent
+                        .spawn(SpriteBundle {
+                            sprite: Sprite {
+                                custom_size: Some(RESULTS_BUTTON_SIZE),
+                                color: RESULTS_BUTTON_COLOR,
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        })
+                        .insert(Transform2::from_xy(x, -280.))
+                        .insert(DepthLayer::Inherit(0.2))
+                        .insert(Interactable::new(
+                            CollisionShape::Aabb {
+                                half_extents: RESULTS_BUTTON_SIZE * 0.5,
+                            },
+                            Vec2::ZERO,
+                        ))
+                        .insert(ResultsButton { kind })
+                        .with_children(|parent| {
+                            parent
+                                .spawn(Text2dBundle {
+                                    text: Text::from_section(label, text_style(50.))
+                                        .with_alignment(TextAlignment::CENTER),
+                                    ..Default::default()
+                                })
+                                .insert(Transform2::default())
+                                .insert(DepthLayer::Inherit(0.1));
+                        });
+                }
+            });
+    }
+}
+
+fn results_button_update(
+    mut results_button_query: Query<(&ResultsButton, &Interactable, &mut Sprite)>,
+    mut app_state: ResMut<State<AppState>>,
+    game_input: Res<GameInput>,
+) {
+    for (results_button, results_button_interactable, mut results_button_sprite) in
+        results_button_query.iter_mut()
+    {
+        results_button_sprite.color = if results_button_interactable.hovered(game_input.as_ref()) {
+            RESULTS_BUTTON_HOVER_COLOR
+        } else {
+            RESULTS_BUTTON_COLOR
+        };
+        if results_button_interactable
+            .drag_started(game_input.as_ref())
+            .is_some()
+        {
+            match results_button.kind {
+                ResultsButtonKind::Retry => {
+                    let _ = app_state.replace(AppState::Game);
+                }
+                ResultsButtonKind::Menu => {
+                    let _ = app_state.replace(AppState::Menu);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,394 @@
+use std::time::Duration;
+
+use bevy::{
+    asset::AssetPlugin,
+    core::CorePlugin,
+    hierarchy::HierarchyPlugin,
+    input::{keyboard::KeyboardInput, ButtonState, InputPlugin},
+    prelude::*,
+    transform::TransformPlugin,
+    utils::Instant,
+};
+use bevy_kira_audio::AudioChannel;
+use bevy_spine::prelude::*;
+
+use crate::{
+    common::{
+        AnnouncementChannel, CollisionShape, CommonPlugins, Config, ConfigPlugin,
+        ForceCameraRatioPlugin, GameInput, GameInputDrag, GameInputDragSource, GameInputFrame,
+        GameInputPlugin, GameInputRecorder, GameInputReplay, GameInputVirtual, GameRng,
+        Interactable, MusicChannel, SfxChannel, Transform2,
+    },
+    game::{
+        Bag, BagCatalog, BagSystem, Container, Conveyor, ConveyorPath, Customer,
+        CustomerSpawnEvent, Desk, DeskSystem, DifficultyCurve, DifficultyInput, DifficultyKey,
+        GameSpawner, GameStatePlugin, Health, Product, ProductCatalog, ProductKind,
+        ProductSpawnEvent, Records, BAG_SEND_BUTTON_OFFSET,
+    },
+    AppState, AssetLibrary,
+};
+
+/// Length of a single [`HeadlessGame`] frame.
+pub const HEADLESS_FRAME: Duration = Duration::from_nanos(16_666_667);
+
+// stand-ins for the bounds and slot bones of the bag skeleton
+const HEADLESS_BAG_OFFSET: Vec2 = Vec2::new(0., 100.);
+const HEADLESS_BAG_HALF_EXTENTS: Vec2 = Vec2::new(200., 200.);
+const HEADLESS_BAG_SLOT_SPACING: f32 = 60.;
+
+const HEADLESS_DRAG_FRAMES: u32 = 10;
+
+/// Builds the game logic without a window, renderer, audio output or Spine runtime, starting
+/// directly in [`AppState::Game`]. Time and [`GameInput`] are left for the caller to drive,
+/// unless a [`GameInputReplay`] is inserted before the first update.
+pub fn headless_app(seed: u64) -> App {
+    let mut app = App::new();
+    app.add_plugin(CorePlugin::default())
+        .add_plugin(TransformPlugin)
+        .add_plugin(HierarchyPlugin)
+        .add_plugin(AssetPlugin::default())
+        .init_resource::<Time>()
+        .add_plugin(InputPlugin)
+        .init_resource::<AudioChannel<SfxChannel>>()
+        .init_resource::<AudioChannel<MusicChannel>>()
+        .init_resource::<AudioChannel<AnnouncementChannel>>()
+        .init_resource::<Config>()
+        .init_resource::<AssetLibrary>()
+        .add_state(AppState::Game)
+        .add_plugins(
+            CommonPlugins
+                .build()
+                .disable::<ConfigPlugin>()
+                .disable::<ForceCameraRatioPlugin>()
+                .disable::<GameInputPlugin>(),
+        )
+        .init_resource::<GameInput>()
+        .init_resource::<GameInputVirtual>()
+        .add_plugin(HeadlessSpinePlugin)
+        .add_plugin(GameStatePlugin)
+        .insert_resource(ProductCatalog::builtin())
+        .insert_resource(BagCatalog::builtin())
+        // never read or write the player's records
+        .insert_resource(Records::default())
+        .insert_resource(GameRng::new(Some(seed)));
+    app
+}
+
+/// Skeletons never load without the Spine runtime, so this gives bags and the desk the
+/// entities that would otherwise be created when their [`SpineReadyEvent`] arrives.
+struct HeadlessSpinePlugin;
+
+impl Plugin for HeadlessSpinePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<SkeletonData>()
+            .add_event::<SpineReadyEvent>()
+            .add_event::<SpineEvent>()
+            .add_system(headless_bag_spawned.after(BagSystem::Spawn))
+            .add_system(headless_desk_spawned.after(DeskSystem::Spawn));
+    }
+}
+
+fn headless_bag_spawned(mut commands: Commands, bag_query: Query<(Entity, &Bag), Added<Bag>>) {
+    for (bag_entity, bag) in bag_query.iter() {
+        let mut slot_entities = vec![];
+        commands.entity(bag_entity).with_children(|parent| {
+            for i in 0..bag.definition.slots {
+                slot_entities.push(
+                    parent
+                        .spawn(TransformBundle::default())
+                        .insert(Transform2::from_xy(
+                            0.,
+                            i as f32 * HEADLESS_BAG_SLOT_SPACING,
+                        ))
+                        .id(),
+                );
+            }
+        });
+        commands
+            .entity(bag_entity)
+            .insert(Interactable::new(
+                CollisionShape::Aabb {
+                    half_extents: HEADLESS_BAG_HALF_EXTENTS,
+                },
+                HEADLESS_BAG_OFFSET,
+            ))
+            .insert(Container::new(slot_entities).with_accepts(bag.definition.accepts.clone()));
+    }
+}
+
+fn headless_desk_spawned(mut commands: Commands, desk_query: Query<Entity, Added<Desk>>) {
+    for desk_entity in desk_query.iter() {
+        commands.entity(desk_entity).with_children(|parent| {
+            parent
+                .spawn(TransformBundle::default())
+                .insert(Transform2::default())
+                .insert(Conveyor::default());
+        });
+    }
+}
+
+/// A shift running in a [`headless_app`] with a fixed frame length and scripted input.
+///
+/// Products and customers are only spawned when asked for, so tests have the conveyor to
+/// themselves.
+pub struct HeadlessGame {
+    pub app: App,
+    startup: Instant,
+    elapsed: Duration,
+    drag_id: u64,
+}
+
+impl HeadlessGame {
+    /// A shift with three paper bags, which take three of anything.
+    pub fn new(seed: u64) -> Self {
+        Self::with_bags(seed, &["paper"; 3])
+    }
+
+    /// A shift with the given bags from the [`BagCatalog`] on the desk, from left to right.
+    pub fn with_bags(seed: u64, lineup: &[&str]) -> Self {
+        let mut app = headless_app(seed);
+        app.world.resource_mut::<BagCatalog>().lineup =
+            lineup.iter().map(|id| id.to_string()).collect();
+        app.world.resource_mut::<GameSpawner>().enabled = false;
+        app.insert_resource(DifficultyCurve {
+            input: DifficultyInput::Time,
+            keys: vec![DifficultyKey {
+                at: 0.,
+                conveyor_speed: 100.,
+                spawn_interval: f32::MAX,
+                customer_interval: f32::MAX,
+                product_weights: Default::default(),
+            }],
+        });
+        Self::from_app(app)
+    }
+
+    /// A shift with customers and products coming as usual, its input recorded to `path`.
+    pub fn recording(seed: u64, path: &str) -> Self {
+        let mut app = headless_app(seed);
+        app.insert_resource(GameInputRecorder::create(path).unwrap());
+        Self::from_app(app)
+    }
+
+    fn from_app(mut app: App) -> Self {
+        let startup = Instant::now();
+        app.insert_resource(Time::new(startup));
+        let mut game = Self {
+            app,
+            startup,
+            elapsed: Duration::ZERO,
+            drag_id: 0,
+        };
+        // the bags and the desk's belt appear over the first few frames
+        game.run_frames(5);
+        game
+    }
+
+    pub fn world(&self) -> &World {
+        &self.app.world
+    }
+
+    pub fn world_mut(&mut self) -> &mut World {
+        &mut self.app.world
+    }
+
+    /// Runs a single frame with the given drags held.
+    pub fn step_with_drags(&mut self, drags: Vec<GameInputDrag>) {
+        let frame = GameInputFrame {
+            delta_nanos: HEADLESS_FRAME.as_nanos() as u64,
+            cursor_position: drags.first().map(|drag| drag.position()),
+            drags,
+        };
+        self.app.world.resource_mut::<GameInput>().replay(&frame);
+        self.elapsed += frame.delta();
+        let instant = self.startup + self.elapsed;
+        self.app
+            .world
+            .resource_mut::<Time>()
+            .update_with_instant(instant);
+        self.app.update();
+    }
+
+    pub fn step(&mut self) {
+        self.step_with_drags(vec![]);
+    }
+
+    pub fn run_frames(&mut self, frames: u32) {
+        for _ in 0..frames {
+            self.step();
+        }
+    }
+
+    pub fn run_for(&mut self, seconds: f32) {
+        self.run_frames((seconds / HEADLESS_FRAME.as_secs_f32()).ceil() as u32);
+    }
+
+    /// Presses at `from`, moves to `to` over a few frames and lets go.
+    pub fn drag(&mut self, from: Vec2, to: Vec2) {
+        let drag = GameInputDrag::new(self.drag_id, GameInputDragSource::Cursor, from);
+        self.drag_id += 1;
+        self.step_with_drags(vec![drag.clone()]);
+        for i in 1..=HEADLESS_DRAG_FRAMES {
+            let position = from.lerp(to, i as f32 / HEADLESS_DRAG_FRAMES as f32);
+            self.step_with_drags(vec![drag.moved(position)]);
+        }
+        self.step_with_drags(vec![drag.moved(to).released()]);
+        self.step();
+    }
+
+    /// Presses `key` for a frame and lets go.
+    pub fn press_key(&mut self, key: KeyCode) {
+        for state in [ButtonState::Pressed, ButtonState::Released] {
+            self.app.world.send_event(KeyboardInput {
+                scan_code: 0,
+                key_code: Some(key),
+                state,
+            });
+            self.step();
+        }
+    }
+
+    /// Puts a product at the start of the desk's belt.
+    pub fn spawn_product(&mut self, kind: ProductKind) -> Entity {
+        let conveyor = self.desk_conveyor();
+        self.spawn_product_on(kind, conveyor)
+    }
+
+    /// Puts a product at the start of `conveyor`.
+    pub fn spawn_product_on(&mut self, kind: ProductKind, conveyor: Entity) -> Entity {
+        let position = self
+            .app
+            .world
+            .get::<Conveyor>(conveyor)
+            .unwrap()
+            .path
+            .position(0.);
+        let entity = self.app.world.spawn_empty().id();
+        self.app.world.send_event(ProductSpawnEvent {
+            entity,
+            position,
+            kind,
+            conveyor,
+        });
+        self.run_frames(3);
+        entity
+    }
+
+    /// The belt running along the desk.
+    pub fn desk_conveyor(&mut self) -> Entity {
+        let mut conveyor_query = self
+            .app
+            .world
+            .query_filtered::<(Entity, &Parent), With<Conveyor>>();
+        conveyor_query
+            .iter(&self.app.world)
+            .find(|(_, parent)| self.app.world.get::<Desk>(parent.get()).is_some())
+            .map(|(entity, _)| entity)
+            .unwrap()
+    }
+
+    /// Lays a belt along `points` that hands its items over to `next`, or lets them run off.
+    pub fn spawn_conveyor(&mut self, points: Vec<Vec2>, next: Option<Entity>) -> Entity {
+        let entity = self
+            .app
+            .world
+            .spawn(TransformBundle::default())
+            .insert(Conveyor {
+                next,
+                ..Conveyor::new(ConveyorPath::new(points))
+            })
+            .id();
+        self.step();
+        entity
+    }
+
+    /// Sends a customer with `order` towards the leftmost free bag.
+    pub fn spawn_customer(&mut self, order: Vec<ProductKind>) -> Entity {
+        let mut customer_query = self.app.world.query_filtered::<Entity, With<Customer>>();
+        let customers = customer_query
+            .iter(&self.app.world)
+            .collect::<Vec<Entity>>();
+        self.app.world.send_event(CustomerSpawnEvent {
+            position: Vec2::new(-1100., 0.),
+            scale: 1.,
+            speed: 150.,
+            silhouette: false,
+            order,
+            bag: None,
+        });
+        self.run_frames(2);
+        customer_query
+            .iter(&self.app.world)
+            .find(|customer| !customers.contains(customer))
+            .unwrap()
+    }
+
+    pub fn drag_product_to_bag(&mut self, product: Entity, bag: Entity) {
+        let from = self.position(product);
+        let to = self.position(bag) + HEADLESS_BAG_OFFSET;
+        self.drag(from, to);
+    }
+
+    /// Presses the button sending off `bag` and lets it leave.
+    pub fn send_bag(&mut self, bag: Entity) {
+        let button = self.position(bag) + BAG_SEND_BUTTON_OFFSET;
+        self.drag(button, button);
+    }
+
+    /// Bags ordered from left to right.
+    pub fn bags(&mut self) -> Vec<Entity> {
+        let mut bags = self
+            .app
+            .world
+            .query_filtered::<(Entity, &GlobalTransform), With<Bag>>()
+            .iter(&self.app.world)
+            .map(|(entity, transform)| (entity, transform.translation().x))
+            .collect::<Vec<(Entity, f32)>>();
+        bags.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+        bags.into_iter().map(|(entity, _)| entity).collect()
+    }
+
+    pub fn position(&self, entity: Entity) -> Vec2 {
+        self.app
+            .world
+            .get::<GlobalTransform>(entity)
+            .map(|transform| transform.translation().truncate())
+            .unwrap_or_default()
+    }
+
+    /// Every product on a belt, in a bag or held, oldest first.
+    pub fn products(&mut self) -> Vec<Entity> {
+        let mut products = self
+            .app
+            .world
+            .query_filtered::<Entity, With<Product>>()
+            .iter(&self.app.world)
+            .collect::<Vec<Entity>>();
+        products.sort();
+        products
+    }
+
+    pub fn container(&self, entity: Entity) -> &Container {
+        self.app.world.get::<Container>(entity).unwrap()
+    }
+
+    pub fn health(&self) -> u8 {
+        self.app.world.resource::<Health>().amount
+    }
+
+    pub fn exists(&self, entity: Entity) -> bool {
+        self.app.world.get_entity(entity).is_some()
+    }
+}
+
+/// Plays the input recorded at `path` back into a fresh shift, stopping once every frame of the
+/// recording has run.
+pub fn headless_replay(seed: u64, path: &str) -> App {
+    let mut app = headless_app(seed);
+    app.insert_resource(GameInputReplay::load(path).unwrap());
+    app.update();
+    while app.world.resource::<GameInputReplay>().remaining() > 0 {
+        app.update();
+    }
+    app
+}
@@ -0,0 +1,296 @@
+use bevy::prelude::*;
+use strum::IntoEnumIterator;
+
+use crate::{
+    common::{CollisionShape, DepthLayer, GameInput, Interactable, Transform2},
+    game::{ProductAttribute, ProductWeight, RuleSet, StackOrder},
+    AppState, AssetLibrary,
+};
+
+const HELP_CELL_SIZE: f32 = 64.;
+const HELP_MATRIX_ORIGIN: Vec2 = Vec2::new(-816., 290.);
+const HELP_ATTRIBUTES_ORIGIN: Vec2 = Vec2::new(200., 290.);
+const HELP_ICON_SCALE: f32 = 0.75;
+const HELP_COMPATIBLE_COLOR: Color = Color::rgba(1., 1., 1., 0.1);
+const HELP_INCOMPATIBLE_COLOR: Color = Color::rgb(0.8, 0.2, 0.2);
+const HELP_BUTTON_SIZE: Vec2 = Vec2::new(200., 80.);
+const HELP_BUTTON_COLOR: Color = Color::rgb(0.25, 0.25, 0.25);
+const HELP_BUTTON_HOVER_COLOR: Color = Color::rgb(0.4, 0.4, 0.4);
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub enum HelpSystem {
+    Spawn,
+    ButtonUpdate,
+}
+
+pub struct HelpPlugin;
+
+impl Plugin for HelpPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<HelpSpawnEvent>()
+            .add_system(help_spawn.label(HelpSystem::Spawn))
+            .add_system(help_button_update.label(HelpSystem::ButtonUpdate));
+    }
+}
+
+#[derive(Default)]
+pub struct HelpSpawnEvent;
+
+#[derive(Component)]
+pub struct Help;
+
+#[derive(Component)]
+struct HelpBackButton;
+
+/// Lines explaining how a stack is judged by the given [`RuleSet`].
+pub fn help_stacking_rules(rule_set: &RuleSet) -> Vec<String> {
+    let mut lines =
+        vec!["Bags are sent off once every slot is full, or early with Send.".to_string()];
+    for order in rule_set.ordering.iter() {
+        match *order {
+            StackOrder::HeavierBelow => {
+                let weights = ProductWeight::iter()
+                    .rev()
+                    .map(|weight| {
+                        let attributes = ProductAttribute::iter()
+                            .filter(|attribute| {
+                                rule_set.attribute_weight(*attribute) == Some(weight)
+                            })
+                            .map(|attribute| format!("{:?}", attribute))
+                            .collect::<Vec<String>>();
+                        if attributes.is_empty() {
+                            format!("{:?}", weight)
+                        } else {
+                            format!("{:?} ({})", weight, attributes.join(", "))
+                        }
+                    })
+                    .collect::<Vec<String>>();
+                lines.push(format!(
+                    "Stack from the bottom up: {}.",
+                    weights.join(" > ")
+                ));
+                lines.push("Nothing may sit on top of something lighter than itself.".to_string());
+            }
+            StackOrder::NotAbove(attribute, other_attribute) => {
+                lines.push(format!(
+                    "Nothing {:?} may sit on top of something {:?}.",
+                    attribute, other_attribute
+                ));
+            }
+        }
+    }
+    lines.push("Every product must be compatible with everything else in its bag.".to_string());
+    lines
+}
+
+fn help_spawn(
+    mut spawn_events: EventReader<HelpSpawnEvent>,
+    mut commands: Commands,
+    rule_set: Res<RuleSet>,
+    asset_library: Res<AssetLibrary>,
+) {
+    for _ in spawn_events.iter() {
+        let text_style = |font_size: f32| TextStyle {
+            font: asset_library.fonts.default.clone(),
+            font_size,
+            color: Color::WHITE,
+        };
+        let attributes = ProductAttribute::iter().collect::<Vec<ProductAttribute>>();
+        commands
+            .spawn(SpriteBundle {
+                sprite: Sprite {
+                    custom_size: Some(Vec2::new(1920., 1080.)),
+                    color: Color::rgb(0.1, 0.1, 0.12),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .insert(Transform2::default())
+            .insert(DepthLayer::Foreground(0.))
+            .insert(Help)
+            .with_children(|parent| {
+                let mut spawn_text =
+                    |text: String, position: Vec2, font_size: f32, alignment: TextAlignment| {
+                        parent
+                            .spawn(Text2dBundle {
+                                text: Text::from_section(text, text_style(font_size))
+                                    .with_alignment(alignment),
+                                ..Default::default()
+                            })
+                            .insert(Transform2::from_translation(position))
+                            .insert(DepthLayer::Inherit(0.3));
+                    };
+                spawn_text(
+                    "How to Bag".to_string(),
+                    Vec2::new(0., 460.),
+                    80.,
+                    TextAlignment::CENTER,
+                );
+                spawn_text(
+                    "Can they share a bag?".to_string(),
+                    Vec2::new(-560., 370.),
+                    40.,
+                    TextAlignment::CENTER,
+                );
+                spawn_text(
+                    "Attributes".to_string(),
+                    Vec2::new(420., 370.),
+                    40.,
+                    TextAlignment::CENTER,
+                );
+                for (i, attribute) in attributes.iter().enumerate() {
+                    let name = if let Some(weight) = rule_set.attribute_weight(*attribute) {
+                        format!("{:?} ({:?} weight)", attribute, weight)
+                    } else {
+                        format!("{:?}", attribute)
+                    };
+                    spawn_text(
+                        name,
+                        HELP_ATTRIBUTES_ORIGIN + Vec2::new(50., -(i as f32) * HELP_CELL_SIZE),
+                        36.,
+                        TextAlignment::CENTER_LEFT,
+                    );
+                }
+                for (i, line) in help_stacking_rules(rule_set.as_ref())
+                    .into_iter()
+                    .enumerate()
+                {
+                    spawn_text(
+                        line,
+                        Vec2::new(0., -340. - i as f32 * 45.),
+                        36.,
+                        TextAlignment::CENTER,
+                    );
+                }
+                spawn_text(
+                    "Incompatible".to_string(),
+                    Vec2::new(-740., -270.),
+                    30.,
+                    TextAlignment::CENTER_LEFT,
+                );
+                spawn_text(
+                    "Compatible".to_string(),
+                    Vec2::new(-480., -270.),
+                    30.,
+                    TextAlignment::CENTER_LEFT,
+                );
+
+                let mut spawn_icon = |attribute: &ProductAttribute, position: Vec2| {
+                    parent
+                        .spawn(SpriteBundle {
+                            texture: attribute.icon(asset_library.as_ref()),
+                            ..Default::default()
+                        })
+                        .insert(
+                            Transform2::from_translation(position)
+                                .with_scale(Vec2::splat(HELP_ICON_SCALE)),
+                        )
+                        .insert(DepthLayer::Inherit(0.2));
+                };
+                for (i, attribute) in attributes.iter().enumerate() {
+                    let offset = (i + 1) as f32 * HELP_CELL_SIZE;
+                    spawn_icon(attribute, HELP_MATRIX_ORIGIN + Vec2::new(offset, 0.));
+                    spawn_icon(attribute, HELP_MATRIX_ORIGIN - Vec2::new(0., offset));
+                    spawn_icon(
+                        attribute,
+                        HELP_ATTRIBUTES_ORIGIN - Vec2::new(0., i as f32 * HELP_CELL_SIZE),
+                    );
+                }
+
+                let mut spawn_cell = |color: Color, position: Vec2| {
+                    parent
+                        .spawn(SpriteBundle {
+                            sprite: Sprite {
+                                custom_size: Some(Vec2::splat(HELP_CELL_SIZE - 6.)),
+                                color,
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        })
+                        .insert(Transform2::from_translation(position))
+                        .insert(DepthLayer::Inherit(0.1));
+                };
+                for (i, attribute) in attributes.iter().enumerate() {
+                    for (j, other_attribute) in attributes.iter().enumerate() {
+                        let color = if rule_set.compatible(*attribute, *other_attribute) {
+                            HELP_COMPATIBLE_COLOR
+                        } else {
+                            HELP_INCOMPATIBLE_COLOR
+                        };
+                        spawn_cell(
+                            color,
+                            HELP_MATRIX_ORIGIN
+                                + Vec2::new(
+                                    (j + 1) as f32 * HELP_CELL_SIZE,
+                                    -((i + 1) as f32) * HELP_CELL_SIZE,
+                                ),
+                        );
+                    }
+                }
+                spawn_cell(HELP_INCOMPATIBLE_COLOR, Vec2::new(-780., -270.));
+                spawn_cell(HELP_COMPATIBLE_COLOR, Vec2::new(-520., -270.));
+
+                parent
+                    .spawn(SpriteBundle {
+                        sprite: Sprite {
+                            custom_size: Some(HELP_BUTTON_SIZE),
+                            color: HELP_BUTTON_COLOR,
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    })
+                    .insert(Transform2::from_xy(-820., 460.))
+                    .insert(DepthLayer::Inherit(0.2))
+                    .insert(Interactable::new(
+                        CollisionShape::Aabb {
+                            half_extents: HELP_BUTTON_SIZE * 0.5,
+                        },
+                        Vec2::ZERO,
+                    ))
+                    .insert(HelpBackButton)
+                    .with_children(|parent| {
+                        parent
+                            .spawn(Text2dBundle {
+                                text: Text::from_section("Back", text_style(50.))
+                                    .with_alignment(TextAlignment::CENTER),
+                                ..Default::default()
+                            })
+                            .insert(Transform2::default())
+                            .insert(DepthLayer::Inherit(0.1));
+                    });
+            });
+    }
+}
+
+fn help_button_update(
+    mut help_button_query: Query<(&Interactable, &mut Sprite), With<HelpBackButton>>,
+    mut app_state: ResMut<State<AppState>>,
+    game_input: Res<GameInput>,
+) {
+    for (help_button_interactable, mut help_button_sprite) in help_button_query.iter_mut() {
+        help_button_sprite.color = if help_button_interactable.hovered(game_input.as_ref()) {
+            HELP_BUTTON_HOVER_COLOR
+        } else {
+            HELP_BUTTON_COLOR
+        };
+        if help_button_interactable
+            .drag_started(game_input.as_ref())
+            .is_some()
+        {
+            let _ = app_state.set(AppState::Menu);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{game::RuleSet, help::help_stacking_rules};
+
+    #[test]
+    fn stacking_rules() {
+        assert_eq!(
+            help_stacking_rules(&RuleSet::builtin())[1],
+            "Stack from the bottom up: Heavy (Heavy) > Normal > Light (Fragile)."
+        );
+    }
+}
@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod help;
+mod plugin;
+
+pub use help::*;
+pub use plugin::*;
@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+
+use crate::AppState;
+
+use super::{HelpPlugin, HelpSpawnEvent, HelpSystem};
+
+pub struct HelpStatePlugin;
+
+impl Plugin for HelpStatePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(HelpPlugin)
+            .add_system_set(
+                SystemSet::on_enter(AppState::Help)
+                    .with_system(help_enter)
+                    .before(HelpSystem::Spawn),
+            )
+            .add_system_set(SystemSet::on_update(AppState::Help).with_system(help_esc_to_menu));
+    }
+}
+
+fn help_enter(mut commands: Commands, mut help_spawn_events: EventWriter<HelpSpawnEvent>) {
+    commands.spawn(Camera2dBundle::default());
+    help_spawn_events.send_default();
+}
+
+fn help_esc_to_menu(mut app_state: ResMut<State<AppState>>, mut input: ResMut<Input<KeyCode>>) {
+    if input.just_pressed(KeyCode::Escape) {
+        let _ = app_state.set(AppState::Menu);
+        input.reset(KeyCode::Escape);
+    }
+}
@@ -6,7 +6,11 @@ use bevy_spine::prelude::*;
 use common::CommonPlugins;
 use winit::window::Icon;
 
-use crate::{game::GameStatePlugin, loading::LoadingStatePlugin, menu::MenuStatePlugin};
+use crate::{
+    game::GameStatePlugin, game_over::GameOverStatePlugin, help::HelpStatePlugin,
+    loading::LoadingStatePlugin, menu::MenuStatePlugin, pause::PauseStatePlugin,
+    settings::SettingsStatePlugin,
+};
 
 pub use crate::{app_state::AppState, asset_library::AssetLibrary};
 
@@ -56,7 +60,10 @@ pub fn game() {
                 ..Default::default()
             })
             .build()
-            .add_before::<bevy::asset::AssetPlugin, _>(EmbeddedAssetIoPlugin),
+            .add_before::<bevy::asset::AssetPlugin, _>(EmbeddedAssetIoPlugin {
+                // the web asset IO fetches over http, so there is no local disk to override from
+                disk_overrides: !cfg!(target_arch = "wasm32"),
+            }),
     );
 
     app.add_plugins(CommonPlugins)
@@ -64,7 +71,11 @@ pub fn game() {
         .add_plugin(SpinePlugin)
         .add_plugin(LoadingStatePlugin)
         .add_plugin(GameStatePlugin)
+        .add_plugin(GameOverStatePlugin)
+        .add_plugin(HelpStatePlugin)
         .add_plugin(MenuStatePlugin)
+        .add_plugin(PauseStatePlugin)
+        .add_plugin(SettingsStatePlugin)
         .add_startup_system(set_window_icon)
         .run();
 }
@@ -92,5 +103,11 @@ pub mod app_state;
 pub mod asset_library;
 pub mod common;
 pub mod game;
+pub mod game_over;
+#[cfg(feature = "headless")]
+pub mod headless;
+pub mod help;
 pub mod loading;
 pub mod menu;
+pub mod pause;
+pub mod settings;
@@ -1,7 +1,21 @@
-use bevy::prelude::*;
-use bevy_spine::prelude::*;
+use std::path::Path;
 
-use crate::{common::VersionSpawnEvent, AppState, AssetLibrary};
+use bevy::{
+    asset::{AssetPath, HandleId, LoadState},
+    prelude::*,
+    sprite::Anchor,
+    utils::HashSet,
+};
+use bevy_spine::{prelude::*, Atlas};
+
+use crate::{
+    common::VersionSpawnEvent,
+    game::{BagCatalog, ProductCatalog, RuleSetVariant},
+    AppState, AssetLibrary,
+};
+
+const LOADING_PROGRESS_WIDTH: f32 = 600.;
+const LOADING_PROGRESS_HEIGHT: f32 = 24.;
 
 pub struct LoadingStatePlugin;
 
@@ -12,18 +26,199 @@ impl Plugin for LoadingStatePlugin {
     }
 }
 
+#[derive(Component)]
+struct LoadingProgressFill;
+
+#[derive(Component)]
+struct LoadingFailedText;
+
 fn loading_enter(
     mut asset_library: ResMut<AssetLibrary>,
     mut skeletons: ResMut<Assets<SkeletonData>>,
     mut version_spawn_events: EventWriter<VersionSpawnEvent>,
+    mut commands: Commands,
     asset_server: Res<AssetServer>,
 ) {
     asset_library.load_assets(skeletons.as_mut(), asset_server.as_ref());
     version_spawn_events.send_default();
+
+    commands.spawn(Camera2dBundle::default());
+    commands
+        .spawn(SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgb(0.15, 0.15, 0.15),
+                custom_size: Some(Vec2::new(LOADING_PROGRESS_WIDTH, LOADING_PROGRESS_HEIGHT)),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            parent
+                .spawn(SpriteBundle {
+                    sprite: Sprite {
+                        color: Color::WHITE,
+                        custom_size: Some(Vec2::new(0., LOADING_PROGRESS_HEIGHT)),
+                        anchor: Anchor::CenterLeft,
+                        ..Default::default()
+                    },
+                    transform: Transform::from_xyz(LOADING_PROGRESS_WIDTH * -0.5, 0., 0.001),
+                    ..Default::default()
+                })
+                .insert(LoadingProgressFill);
+        });
+    commands
+        .spawn(Text2dBundle {
+            transform: Transform::from_xyz(0., -50., 0.),
+            text: Text::from_section(
+                "",
+                TextStyle {
+                    font: asset_library.fonts.default.clone(),
+                    font_size: 30.,
+                    color: Color::rgb(1., 0.3, 0.3),
+                },
+            )
+            .with_alignment(TextAlignment {
+                horizontal: HorizontalAlign::Center,
+                vertical: VerticalAlign::Top,
+            }),
+            ..Default::default()
+        })
+        .insert(LoadingFailedText);
 }
 
-fn loading_update(mut app_state: ResMut<State<AppState>>, time: Res<Time>) {
-    if time.elapsed_seconds() > 0.2 {
+#[allow(clippy::too_many_arguments)]
+fn loading_update(
+    mut app_state: ResMut<State<AppState>>,
+    mut fill_query: Query<&mut Sprite, With<LoadingProgressFill>>,
+    mut failed_text_query: Query<&mut Text, With<LoadingFailedText>>,
+    mut reported_failures: Local<HashSet<String>>,
+    asset_library: Res<AssetLibrary>,
+    asset_server: Res<AssetServer>,
+    product_catalog: Res<ProductCatalog>,
+    bag_catalog: Res<BagCatalog>,
+    rule_set_variant: Res<RuleSetVariant>,
+    product_catalogs: Res<Assets<ProductCatalog>>,
+    bag_catalogs: Res<Assets<BagCatalog>>,
+    skeletons: Res<Assets<SkeletonData>>,
+    atlases: Res<Assets<Atlas>>,
+) {
+    let mut total = 0;
+    let mut loaded = 0;
+    let mut failed = vec![];
+
+    for handle in asset_library
+        .handles()
+        .into_iter()
+        .chain(product_catalog.handles())
+        .chain(bag_catalog.handles())
+        .chain(rule_set_variant.handles())
+    {
+        total += 1;
+        match asset_server.get_load_state(&handle) {
+            LoadState::Loaded => loaded += 1,
+            LoadState::Failed => failed.push(loading_asset_path(asset_server.as_ref(), &handle)),
+            _ => {}
+        }
+    }
+
+    // product and bag skeletons are only known once their catalogs have been made active, so
+    // each catalog counts as a single pending asset until then. Catalogs that fail validation
+    // are never made active.
+    let catalogs = [
+        (
+            asset_library.data.products.id(),
+            product_catalog.products.is_empty(),
+            product_catalogs
+                .get(&asset_library.data.products)
+                .and_then(|catalog| catalog.validate().err()),
+        ),
+        (
+            asset_library.data.bags.id(),
+            bag_catalog.bags.is_empty(),
+            bag_catalogs
+                .get(&asset_library.data.bags)
+                .and_then(|catalog| catalog.validate().err()),
+        ),
+    ];
+    for (id, pending, error) in catalogs {
+        if pending {
+            total += 1;
+        }
+        if let Some(error) = error {
+            failed.push(format!(
+                "{} ({})",
+                loading_asset_path(asset_server.as_ref(), id),
+                error
+            ));
+        }
+    }
+
+    // skeleton files are counted with the handles above, but the texture pages of their atlases
+    // are only known once the atlas has loaded, so each atlas counts as a single pending page
+    // until then
+    for handle in asset_library
+        .skeletons()
+        .into_iter()
+        .chain(product_catalog.skeletons())
+        .chain(bag_catalog.skeletons())
+    {
+        let atlas_handle = match skeletons.get(&handle) {
+            Some(SkeletonData::JsonFile { atlas, .. } | SkeletonData::BinaryFile { atlas, .. }) => {
+                atlas
+            }
+            None => {
+                total += 1;
+                continue;
+            }
+        };
+        let directory = asset_server
+            .get_handle_path(atlas_handle)
+            .and_then(|path| path.path().parent().map(Path::to_path_buf))
+            .unwrap_or_default();
+        if let Some(atlas) = atlases.get(atlas_handle) {
+            for page in atlas.atlas.pages() {
+                let path = directory.join(page.name());
+                total += 1;
+                match asset_server.get_load_state(AssetPath::from(path.as_path())) {
+                    LoadState::Loaded => loaded += 1,
+                    LoadState::Failed => failed.push(path.display().to_string()),
+                    _ => {}
+                }
+            }
+        } else {
+            total += 1;
+        }
+    }
+
+    for mut fill_sprite in fill_query.iter_mut() {
+        let progress = loaded as f32 / total.max(1) as f32;
+        fill_sprite.custom_size = Some(Vec2::new(
+            LOADING_PROGRESS_WIDTH * progress,
+            LOADING_PROGRESS_HEIGHT,
+        ));
+    }
+
+    if !failed.is_empty() {
+        for failure in failed.iter() {
+            if reported_failures.insert(failure.clone()) {
+                error!("Failed to load asset: {}", failure);
+            }
+        }
+        for mut failed_text in failed_text_query.iter_mut() {
+            failed_text.sections[0].value = format!("Failed to load:\n{}", failed.join("\n"));
+        }
+        return;
+    }
+
+    if loaded == total {
         let _ = app_state.set(AppState::Menu);
     }
 }
+
+fn loading_asset_path(asset_server: &AssetServer, id: impl Into<HandleId>) -> String {
+    let id = id.into();
+    asset_server
+        .get_handle_path(id)
+        .map(|path| path.path().display().to_string())
+        .unwrap_or_else(|| format!("{:?}", id))
+}
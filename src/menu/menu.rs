@@ -2,10 +2,15 @@ use bevy::{app::AppExit, prelude::*};
 use bevy_spine::prelude::*;
 
 use crate::{
-    common::{Aabb, CollisionShape, GameInput, Interactable, Transform2},
+    common::{CollisionShape, DepthLayer, GameInput, Interactable, Transform2},
+    game::Records,
     AppState, AssetLibrary,
 };
 
+const MENU_SETTINGS_BUTTON_SIZE: Vec2 = Vec2::new(240., 80.);
+const MENU_SETTINGS_BUTTON_COLOR: Color = Color::rgb(0.25, 0.25, 0.25);
+const MENU_SETTINGS_BUTTON_HOVER_COLOR: Color = Color::rgb(0.4, 0.4, 0.4);
+
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
 pub enum MenuSystem {
     Spawn,
@@ -20,7 +25,8 @@ impl Plugin for MenuPlugin {
         app.add_event::<MenuSpawnEvent>()
             .add_system(menu_spawn.label(MenuSystem::Spawn))
             .add_system(menu_spawned.label(MenuSystem::Spawned))
-            .add_system(menu_button_update.label(MenuSystem::ButtonUpdate));
+            .add_system(menu_button_update.label(MenuSystem::ButtonUpdate))
+            .add_system(menu_settings_button_update.label(MenuSystem::ButtonUpdate));
     }
 }
 
@@ -36,6 +42,10 @@ struct MenuButton {
     slot: SlotHandle,
 }
 
+/// The menu skeleton has no settings slot, so this button is drawn on top of it.
+#[derive(Component)]
+struct MenuSettingsButton;
+
 #[derive(Component)]
 enum MenuButtonKind {
     Play,
@@ -55,7 +65,44 @@ fn menu_spawn(
                 ..Default::default()
             })
             .insert(Transform2::default())
-            .insert(Menu);
+            .insert(Menu)
+            .with_children(|parent| {
+                parent
+                    .spawn(SpriteBundle {
+                        sprite: Sprite {
+                            custom_size: Some(MENU_SETTINGS_BUTTON_SIZE),
+                            color: MENU_SETTINGS_BUTTON_COLOR,
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    })
+                    .insert(Transform2::from_xy(-800., -470.))
+                    .insert(DepthLayer::Foreground(0.5))
+                    .insert(Interactable::new(
+                        CollisionShape::Aabb {
+                            half_extents: MENU_SETTINGS_BUTTON_SIZE * 0.5,
+                        },
+                        Vec2::ZERO,
+                    ))
+                    .insert(MenuSettingsButton)
+                    .with_children(|parent| {
+                        parent
+                            .spawn(Text2dBundle {
+                                text: Text::from_section(
+                                    "Settings",
+                                    TextStyle {
+                                        font: asset_library.fonts.default.clone(),
+                                        font_size: 50.,
+                                        color: Color::WHITE,
+                                    },
+                                )
+                                .with_alignment(TextAlignment::CENTER),
+                                ..Default::default()
+                            })
+                            .insert(Transform2::default())
+                            .insert(DepthLayer::Inherit(0.1));
+                    });
+            });
     }
 }
 
@@ -63,36 +110,50 @@ fn menu_spawned(
     mut spine_ready_event: EventReader<SpineReadyEvent>,
     mut commands: Commands,
     mut menu_query: Query<&mut Spine, &Menu>,
+    records: Res<Records>,
+    asset_library: Res<AssetLibrary>,
 ) {
     for event in spine_ready_event.iter() {
-        if let Some(menu_spine) = menu_query.get_mut(event.entity).ok() {
+        if let Ok(menu_spine) = menu_query.get_mut(event.entity) {
             commands.entity(event.entity).with_children(|parent| {
+                if records.shifts_played > 0 {
+                    parent
+                        .spawn(Text2dBundle {
+                            text: Text::from_section(
+                                format!("Best score: {}", records.best_score),
+                                TextStyle {
+                                    font: asset_library.fonts.default.clone(),
+                                    font_size: 50.,
+                                    color: Color::WHITE,
+                                },
+                            )
+                            .with_alignment(TextAlignment::TOP_RIGHT),
+                            ..Default::default()
+                        })
+                        .insert(Transform2::from_xy(930., 510.))
+                        .insert(DepthLayer::Foreground(0.5));
+                }
                 for (slot_name, bounds_name, kind) in [
                     ("play", "play_bounds", MenuButtonKind::Play),
                     ("help", "help_bounds", MenuButtonKind::Help),
                     ("quit", "quit_bounds", MenuButtonKind::Quit),
                 ] {
                     let slot = menu_spine.skeleton.find_slot(slot_name).unwrap().handle();
-                    let bounds = Aabb::new_from_vertices(
-                        &menu_spine
-                            .skeleton
-                            .find_slot(bounds_name)
-                            .unwrap()
-                            .bounding_box_attachment()
-                            .unwrap()
-                            .vertices2()
-                            .iter()
-                            .map(|vec| Vec2::from(*vec))
-                            .collect::<Vec<Vec2>>(),
-                    )
-                    .unwrap();
+                    let bounds = menu_spine
+                        .skeleton
+                        .find_slot(bounds_name)
+                        .unwrap()
+                        .bounding_box_attachment()
+                        .unwrap()
+                        .vertices2()
+                        .iter()
+                        .map(|vec| Vec2::from(*vec))
+                        .collect::<Vec<Vec2>>();
                     parent
                         .spawn(TransformBundle::default())
-                        .insert(Transform2::from_translation(bounds.translation))
+                        .insert(Transform2::default())
                         .insert(Interactable::new(
-                            CollisionShape::Aabb {
-                                half_extents: bounds.half_extents,
-                            },
+                            CollisionShape::polygon(bounds),
                             Vec2::ZERO,
                         ))
                         .insert(MenuButton { kind, slot });
@@ -110,7 +171,7 @@ fn menu_button_update(
     game_input: Res<GameInput>,
 ) {
     for (menu_button, menu_button_interactable, menu_button_parent) in menu_button_query.iter() {
-        if let Some(mut menu_spine) = spine_query.get_mut(menu_button_parent.get()).ok() {
+        if let Ok(mut menu_spine) = spine_query.get_mut(menu_button_parent.get()) {
             menu_button
                 .slot
                 .get_mut(&mut menu_spine.skeleton)
@@ -129,7 +190,9 @@ fn menu_button_update(
                     MenuButtonKind::Play => {
                         let _ = app_state.set(AppState::Game);
                     }
-                    MenuButtonKind::Help => {}
+                    MenuButtonKind::Help => {
+                        let _ = app_state.set(AppState::Help);
+                    }
                     MenuButtonKind::Quit => {
                         exit_events.send_default();
                     }
@@ -138,3 +201,26 @@ fn menu_button_update(
         }
     }
 }
+
+fn menu_settings_button_update(
+    mut settings_button_query: Query<(&Interactable, &mut Sprite), With<MenuSettingsButton>>,
+    mut app_state: ResMut<State<AppState>>,
+    game_input: Res<GameInput>,
+) {
+    for (settings_button_interactable, mut settings_button_sprite) in
+        settings_button_query.iter_mut()
+    {
+        settings_button_sprite.color = if settings_button_interactable.hovered(game_input.as_ref())
+        {
+            MENU_SETTINGS_BUTTON_HOVER_COLOR
+        } else {
+            MENU_SETTINGS_BUTTON_COLOR
+        };
+        if settings_button_interactable
+            .drag_started(game_input.as_ref())
+            .is_some()
+        {
+            let _ = app_state.set(AppState::Settings);
+        }
+    }
+}
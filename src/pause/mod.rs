@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod pause;
+mod plugin;
+
+pub use pause::*;
+pub use plugin::*;
@@ -0,0 +1,163 @@
+use bevy::prelude::*;
+
+use crate::{
+    common::{CollisionShape, DepthLayer, GameInput, Interactable, Transform2},
+    game::DEPTH_PAUSE,
+    AppState, AssetLibrary,
+};
+
+const PAUSE_PANEL_SIZE: Vec2 = Vec2::new(600., 600.);
+const PAUSE_BUTTON_SIZE: Vec2 = Vec2::new(400., 90.);
+const PAUSE_BUTTON_COLOR: Color = Color::rgb(0.25, 0.25, 0.25);
+const PAUSE_BUTTON_HOVER_COLOR: Color = Color::rgb(0.4, 0.4, 0.4);
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub enum PauseSystem {
+    Spawn,
+    ButtonUpdate,
+}
+
+pub struct PausePlugin;
+
+impl Plugin for PausePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PauseSpawnEvent>()
+            .add_system(pause_spawn.label(PauseSystem::Spawn))
+            .add_system(pause_button_update.label(PauseSystem::ButtonUpdate));
+    }
+}
+
+#[derive(Default)]
+pub struct PauseSpawnEvent;
+
+#[derive(Component)]
+pub struct Pause;
+
+#[derive(Component)]
+struct PauseButton {
+    kind: PauseButtonKind,
+}
+
+#[derive(Clone, Copy)]
+enum PauseButtonKind {
+    Resume,
+    Settings,
+    Quit,
+}
+
+fn pause_spawn(
+    mut spawn_events: EventReader<PauseSpawnEvent>,
+    mut commands: Commands,
+    asset_library: Res<AssetLibrary>,
+) {
+    for _ in spawn_events.iter() {
+        let text_style = |font_size: f32| TextStyle {
+            font: asset_library.fonts.default.clone(),
+            font_size,
+            color: Color::WHITE,
+        };
+        commands
+            .spawn(SpriteBundle {
+                sprite: Sprite {
+                    custom_size: Some(Vec2::new(1920., 1080.)),
+                    color: Color::rgba(0., 0., 0., 0.6),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .insert(Transform2::default())
+            .insert(DEPTH_PAUSE)
+            .insert(Pause)
+            .with_children(|parent| {
+                parent
+                    .spawn(SpriteBundle {
+                        sprite: Sprite {
+                            custom_size: Some(PAUSE_PANEL_SIZE),
+                            color: Color::rgba(0.1, 0.1, 0.1, 0.95),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    })
+                    .insert(Transform2::default())
+                    .insert(DepthLayer::Inherit(0.1));
+                parent
+                    .spawn(Text2dBundle {
+                        text: Text::from_section("Paused", text_style(80.))
+                            .with_alignment(TextAlignment::CENTER),
+                        ..Default::default()
+                    })
+                    .insert(Transform2::from_xy(0., 200.))
+                    .insert(DepthLayer::Inherit(0.2));
+                for (y, label, kind) in [
+                    (50., "Resume", PauseButtonKind::Resume),
+                    (-70., "Settings", PauseButtonKind::Settings),
+                    (-190., "Quit to menu", PauseButtonKind::Quit),
+                ] {
+                    parent
+                        .spawn(SpriteBundle {
+                            sprite: Sprite {
+                                custom_size: Some(PAUSE_BUTTON_SIZE),
+                                color: PAUSE_BUTTON_COLOR,
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        })
+                        .insert(Transform2::from_xy(0., y))
+                        .insert(DepthLayer::Inherit(0.2))
+                        .insert(Interactable::new(
+                            CollisionShape::Aabb {
+                                half_extents: PAUSE_BUTTON_SIZE * 0.5,
+                            },
+                            Vec2::ZERO,
+                        ))
+                        .insert(PauseButton { kind })
+                        .with_children(|parent| {
+                            parent
+                                .spawn(Text2dBundle {
+                                    text: Text::from_section(label, text_style(50.))
+                                        .with_alignment(TextAlignment::CENTER),
+                                    ..Default::default()
+                                })
+                                .insert(Transform2::default())
+                                .insert(DepthLayer::Inherit(0.1));
+                        });
+                }
+            });
+    }
+}
+
+fn pause_button_update(
+    mut pause_button_query: Query<(&PauseButton, &Interactable, &mut Sprite)>,
+    mut app_state: ResMut<State<AppState>>,
+    game_input: Res<GameInput>,
+) {
+    // the buttons stay around underneath the settings screen
+    if *app_state.current() != AppState::Paused {
+        return;
+    }
+    for (pause_button, pause_button_interactable, mut pause_button_sprite) in
+        pause_button_query.iter_mut()
+    {
+        pause_button_sprite.color = if pause_button_interactable.hovered(game_input.as_ref()) {
+            PAUSE_BUTTON_HOVER_COLOR
+        } else {
+            PAUSE_BUTTON_COLOR
+        };
+        if pause_button_interactable
+            .drag_started(game_input.as_ref())
+            .is_some()
+        {
+            match pause_button.kind {
+                PauseButtonKind::Resume => {
+                    let _ = app_state.pop();
+                }
+                PauseButtonKind::Settings => {
+                    let _ = app_state.push(AppState::Settings);
+                }
+                PauseButtonKind::Quit => {
+                    let _ = app_state.replace(AppState::Menu);
+                }
+            }
+        }
+    }
+}
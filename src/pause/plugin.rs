@@ -0,0 +1,83 @@
+use bevy::{prelude::*, window::WindowFocused};
+use bevy_kira_audio::{AudioChannel, AudioControl};
+
+use crate::{
+    common::{AnnouncementChannel, MusicChannel, SfxChannel},
+    game::ProductDrag,
+    AppState,
+};
+
+use super::{Pause, PausePlugin, PauseSpawnEvent, PauseSystem};
+
+pub struct PauseStatePlugin;
+
+impl Plugin for PauseStatePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(PausePlugin)
+            .add_system_set(
+                SystemSet::on_enter(AppState::Paused)
+                    .with_system(pause_enter)
+                    .before(PauseSystem::Spawn),
+            )
+            .add_system_set(SystemSet::on_update(AppState::Paused).with_system(pause_esc_to_resume))
+            .add_system_set(SystemSet::on_exit(AppState::Paused).with_system(pause_exit))
+            .add_system(pause_focus_lost);
+    }
+}
+
+// Everything in the shift advances with `Time`, so stopping the clock freezes the conveyor,
+// customers, timers and Spine animations alike. Held products are let go, so they can't be
+// dropped into a bag while the shift is frozen.
+fn pause_enter(
+    mut commands: Commands,
+    mut pause_spawn_events: EventWriter<PauseSpawnEvent>,
+    mut time: ResMut<Time>,
+    product_drag_query: Query<Entity, With<ProductDrag>>,
+    sfx_channel: Res<AudioChannel<SfxChannel>>,
+    music_channel: Res<AudioChannel<MusicChannel>>,
+    announcement_channel: Res<AudioChannel<AnnouncementChannel>>,
+) {
+    for product_entity in product_drag_query.iter() {
+        commands.entity(product_entity).remove::<ProductDrag>();
+    }
+    time.pause();
+    sfx_channel.pause();
+    music_channel.pause();
+    announcement_channel.pause();
+    pause_spawn_events.send_default();
+}
+
+fn pause_esc_to_resume(mut app_state: ResMut<State<AppState>>, mut input: ResMut<Input<KeyCode>>) {
+    if input.just_pressed(KeyCode::Escape) {
+        let _ = app_state.pop();
+        input.reset(KeyCode::Escape);
+    }
+}
+
+fn pause_exit(
+    mut commands: Commands,
+    mut time: ResMut<Time>,
+    pause_query: Query<Entity, With<Pause>>,
+    sfx_channel: Res<AudioChannel<SfxChannel>>,
+    music_channel: Res<AudioChannel<MusicChannel>>,
+    announcement_channel: Res<AudioChannel<AnnouncementChannel>>,
+) {
+    for entity in pause_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    time.unpause();
+    sfx_channel.resume();
+    music_channel.resume();
+    announcement_channel.resume();
+}
+
+fn pause_focus_lost(
+    mut focus_events: EventReader<WindowFocused>,
+    mut app_state: ResMut<State<AppState>>,
+) {
+    for event in focus_events.iter() {
+        if !event.focused && *app_state.current() == AppState::Game {
+            let _ = app_state.push(AppState::Paused);
+        }
+    }
+}
@@ -0,0 +1,6 @@
+mod plugin;
+#[allow(clippy::module_inception)]
+mod settings;
+
+pub use plugin::*;
+pub use settings::*;
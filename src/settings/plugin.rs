@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+
+use crate::{common::Config, AppState};
+
+use super::{settings_leave, Settings, SettingsPlugin, SettingsSpawnEvent, SettingsSystem};
+
+pub struct SettingsStatePlugin;
+
+impl Plugin for SettingsStatePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(SettingsPlugin)
+            .add_system_set(
+                SystemSet::on_enter(AppState::Settings)
+                    .with_system(settings_enter)
+                    .before(SettingsSystem::Spawn),
+            )
+            .add_system_set(SystemSet::on_update(AppState::Settings).with_system(settings_esc))
+            .add_system_set(SystemSet::on_exit(AppState::Settings).with_system(settings_exit));
+    }
+}
+
+fn settings_enter(
+    mut commands: Commands,
+    mut settings_spawn_events: EventWriter<SettingsSpawnEvent>,
+    app_state: Res<State<AppState>>,
+) {
+    // when pushed over the paused game, its camera is still around
+    if app_state.inactives().is_empty() {
+        commands.spawn(Camera2dBundle::default()).insert(Settings);
+    }
+    settings_spawn_events.send_default();
+}
+
+fn settings_esc(mut app_state: ResMut<State<AppState>>, mut input: ResMut<Input<KeyCode>>) {
+    if input.just_pressed(KeyCode::Escape) {
+        settings_leave(app_state.as_mut());
+        input.reset(KeyCode::Escape);
+    }
+}
+
+fn settings_exit(
+    mut commands: Commands,
+    settings_query: Query<Entity, With<Settings>>,
+    config: Res<Config>,
+) {
+    for entity in settings_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    if let Err(error) = config.save() {
+        error!("Failed to save settings: {}", error);
+    }
+}
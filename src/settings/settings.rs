@@ -0,0 +1,306 @@
+use bevy::{ecs::system::EntityCommands, prelude::*};
+use strum::IntoEnumIterator;
+
+use crate::{
+    common::{
+        CollisionShape, Config, DepthLayer, GameInput, Interactable, Transform2, VolumeControl,
+    },
+    AppState, AssetLibrary,
+};
+
+const SETTINGS_ROW_ORIGIN: Vec2 = Vec2::new(-420., 250.);
+const SETTINGS_ROW_SPACING: f32 = 110.;
+const SETTINGS_VALUE_X: f32 = 300.;
+const SETTINGS_VOLUME_STEP: f32 = 0.1;
+const SETTINGS_SMALL_BUTTON_SIZE: Vec2 = Vec2::new(80., 80.);
+const SETTINGS_BUTTON_SIZE: Vec2 = Vec2::new(200., 80.);
+const SETTINGS_BUTTON_COLOR: Color = Color::rgb(0.25, 0.25, 0.25);
+const SETTINGS_BUTTON_HOVER_COLOR: Color = Color::rgb(0.4, 0.4, 0.4);
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemLabel)]
+pub enum SettingsSystem {
+    Spawn,
+    ButtonUpdate,
+    ValueUpdate,
+}
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SettingsSpawnEvent>()
+            .add_system(settings_spawn.label(SettingsSystem::Spawn))
+            .add_system(settings_button_update.label(SettingsSystem::ButtonUpdate))
+            .add_system(
+                settings_value_update
+                    .label(SettingsSystem::ValueUpdate)
+                    .after(SettingsSystem::ButtonUpdate),
+            );
+    }
+}
+
+#[derive(Default)]
+pub struct SettingsSpawnEvent;
+
+#[derive(Component)]
+pub struct Settings;
+
+#[derive(Component, Clone, Copy)]
+enum SettingsButton {
+    VolumeDown(VolumeControl),
+    VolumeUp(VolumeControl),
+    Fullscreen,
+    Mute,
+    Back,
+}
+
+/// Text showing the current value of a setting.
+#[derive(Component, Clone, Copy)]
+enum SettingsValue {
+    Volume(VolumeControl),
+    Fullscreen,
+    Mute,
+}
+
+impl SettingsValue {
+    fn text(&self, config: &Config) -> String {
+        let on_off = |value: bool| if value { "On" } else { "Off" };
+        match *self {
+            SettingsValue::Volume(control) => {
+                format!("{}%", (config.volume(control) * 100.).round() as u32)
+            }
+            SettingsValue::Fullscreen => on_off(config.fullscreen).to_string(),
+            SettingsValue::Mute => on_off(config.muted).to_string(),
+        }
+    }
+}
+
+fn settings_spawn(
+    mut spawn_events: EventReader<SettingsSpawnEvent>,
+    mut commands: Commands,
+    config: Res<Config>,
+    asset_library: Res<AssetLibrary>,
+) {
+    for _ in spawn_events.iter() {
+        let text_style = |font_size: f32| TextStyle {
+            font: asset_library.fonts.default.clone(),
+            font_size,
+            color: Color::WHITE,
+        };
+        commands
+            .spawn(SpriteBundle {
+                sprite: Sprite {
+                    custom_size: Some(Vec2::new(1920., 1080.)),
+                    color: Color::rgb(0.1, 0.1, 0.12),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .insert(Transform2::default())
+            // above the game and pause overlay when opened mid-shift
+            .insert(DepthLayer::Foreground(0.7))
+            .insert(Settings)
+            .with_children(|parent| {
+                settings_spawn_text(
+                    parent,
+                    "Settings".to_string(),
+                    text_style(80.),
+                    Vec2::new(0., 420.),
+                    TextAlignment::CENTER,
+                );
+                let mut row_position = SETTINGS_ROW_ORIGIN;
+                let mut spawn_label = |parent: &mut ChildBuilder, name: &str| {
+                    settings_spawn_text(
+                        parent,
+                        name.to_string(),
+                        text_style(44.),
+                        row_position,
+                        TextAlignment::CENTER_LEFT,
+                    );
+                    let value_position = Vec2::new(SETTINGS_VALUE_X, row_position.y);
+                    row_position.y -= SETTINGS_ROW_SPACING;
+                    value_position
+                };
+
+                for control in VolumeControl::iter() {
+                    let value_position = spawn_label(parent, control.name());
+                    let value = SettingsValue::Volume(control);
+                    settings_spawn_text(
+                        parent,
+                        value.text(config.as_ref()),
+                        text_style(44.),
+                        value_position,
+                        TextAlignment::CENTER,
+                    )
+                    .insert(value);
+                    settings_spawn_button(
+                        parent,
+                        SettingsButton::VolumeDown(control),
+                        "-".to_string(),
+                        None,
+                        text_style(50.),
+                        SETTINGS_SMALL_BUTTON_SIZE,
+                        value_position - Vec2::new(140., 0.),
+                    );
+                    settings_spawn_button(
+                        parent,
+                        SettingsButton::VolumeUp(control),
+                        "+".to_string(),
+                        None,
+                        text_style(50.),
+                        SETTINGS_SMALL_BUTTON_SIZE,
+                        value_position + Vec2::new(140., 0.),
+                    );
+                }
+                for (name, button, value) in [
+                    (
+                        "Fullscreen",
+                        SettingsButton::Fullscreen,
+                        SettingsValue::Fullscreen,
+                    ),
+                    ("Mute", SettingsButton::Mute, SettingsValue::Mute),
+                ] {
+                    let value_position = spawn_label(parent, name);
+                    settings_spawn_button(
+                        parent,
+                        button,
+                        value.text(config.as_ref()),
+                        Some(value),
+                        text_style(44.),
+                        SETTINGS_BUTTON_SIZE,
+                        value_position,
+                    );
+                }
+
+                settings_spawn_button(
+                    parent,
+                    SettingsButton::Back,
+                    "Back".to_string(),
+                    None,
+                    text_style(50.),
+                    SETTINGS_BUTTON_SIZE,
+                    Vec2::new(-820., 460.),
+                );
+            });
+    }
+}
+
+fn settings_spawn_text<'w, 's, 'a>(
+    parent: &'a mut ChildBuilder<'w, 's, '_>,
+    text: String,
+    text_style: TextStyle,
+    position: Vec2,
+    alignment: TextAlignment,
+) -> EntityCommands<'w, 's, 'a> {
+    let mut entity_commands = parent.spawn(Text2dBundle {
+        text: Text::from_section(text, text_style).with_alignment(alignment),
+        ..Default::default()
+    });
+    entity_commands
+        .insert(Transform2::from_translation(position))
+        .insert(DepthLayer::Inherit(0.3));
+    entity_commands
+}
+
+fn settings_spawn_button(
+    parent: &mut ChildBuilder,
+    button: SettingsButton,
+    label: String,
+    value: Option<SettingsValue>,
+    text_style: TextStyle,
+    size: Vec2,
+    position: Vec2,
+) {
+    parent
+        .spawn(SpriteBundle {
+            sprite: Sprite {
+                custom_size: Some(size),
+                color: SETTINGS_BUTTON_COLOR,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .insert(Transform2::from_translation(position))
+        .insert(DepthLayer::Inherit(0.2))
+        .insert(Interactable::new(
+            CollisionShape::Aabb {
+                half_extents: size * 0.5,
+            },
+            Vec2::ZERO,
+        ))
+        .insert(button)
+        .with_children(|parent| {
+            let mut label_commands = parent.spawn(Text2dBundle {
+                text: Text::from_section(label, text_style).with_alignment(TextAlignment::CENTER),
+                ..Default::default()
+            });
+            label_commands
+                .insert(Transform2::default())
+                .insert(DepthLayer::Inherit(0.1));
+            if let Some(value) = value {
+                label_commands.insert(value);
+            }
+        });
+}
+
+fn settings_button_update(
+    mut settings_button_query: Query<(&SettingsButton, &Interactable, &mut Sprite)>,
+    mut app_state: ResMut<State<AppState>>,
+    mut config: ResMut<Config>,
+    game_input: Res<GameInput>,
+) {
+    for (settings_button, settings_button_interactable, mut settings_button_sprite) in
+        settings_button_query.iter_mut()
+    {
+        settings_button_sprite.color = if settings_button_interactable.hovered(game_input.as_ref())
+        {
+            SETTINGS_BUTTON_HOVER_COLOR
+        } else {
+            SETTINGS_BUTTON_COLOR
+        };
+        if settings_button_interactable
+            .drag_started(game_input.as_ref())
+            .is_none()
+        {
+            continue;
+        }
+        match *settings_button {
+            SettingsButton::VolumeDown(control) => {
+                let volume = config.volume(control) - SETTINGS_VOLUME_STEP;
+                config.set_volume(control, (volume * 10.).round() / 10.);
+            }
+            SettingsButton::VolumeUp(control) => {
+                let volume = config.volume(control) + SETTINGS_VOLUME_STEP;
+                config.set_volume(control, (volume * 10.).round() / 10.);
+            }
+            SettingsButton::Fullscreen => {
+                config.fullscreen = !config.fullscreen;
+            }
+            SettingsButton::Mute => {
+                config.muted = !config.muted;
+            }
+            SettingsButton::Back => {
+                settings_leave(app_state.as_mut());
+            }
+        }
+    }
+}
+
+/// Returns to whatever state opened the settings: the pause overlay pushes them on top of the
+/// game, while the menu switches to them.
+pub fn settings_leave(app_state: &mut State<AppState>) {
+    if app_state.inactives().is_empty() {
+        let _ = app_state.set(AppState::Menu);
+    } else {
+        let _ = app_state.pop();
+    }
+}
+
+fn settings_value_update(mut value_query: Query<(&SettingsValue, &mut Text)>, config: Res<Config>) {
+    if !config.is_changed() {
+        return;
+    }
+    for (value, mut text) in value_query.iter_mut() {
+        text.sections[0].value = value.text(config.as_ref());
+    }
+}
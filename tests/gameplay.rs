@@ -0,0 +1,357 @@
+use bevy::{
+    app::AppExit,
+    prelude::{KeyCode, Vec2},
+};
+use tale_of_the_bagger::{
+    game::{
+        ConveyorItem, Customer, CustomerOrder, Focus, Health, ProductKind, Records, Score,
+        ShiftStats,
+    },
+    headless::{headless_replay, HeadlessGame},
+};
+
+#[test]
+fn invalid_stack_costs_one_health() {
+    let mut game = HeadlessGame::new(1);
+    let bag = game.bags()[0];
+    let health = game.health();
+
+    let mut products = vec![];
+    for kind in [
+        ProductKind::new("jerky"),
+        ProductKind::new("jerky"),
+        ProductKind::new("cinder"),
+    ] {
+        let product = game.spawn_product(kind);
+        game.drag_product_to_bag(product, bag);
+        products.push(product);
+    }
+    game.run_frames(2);
+
+    assert_eq!(game.health(), health - 1);
+    assert!(game.container(bag).products().is_empty());
+    assert!(game
+        .container(bag)
+        .slots
+        .iter()
+        .all(|slot| slot.product_entity.is_none()));
+    assert!(products.iter().all(|product| !game.exists(*product)));
+    assert_eq!(game.world().resource::<ShiftStats>().bags_failed, 1);
+}
+
+#[test]
+fn partial_stack_stays_in_bag() {
+    let mut game = HeadlessGame::new(1);
+    let bag = game.bags()[1];
+    let health = game.health();
+
+    for kind in [ProductKind::new("jerky"), ProductKind::new("jerky")] {
+        let product = game.spawn_product(kind);
+        game.drag_product_to_bag(product, bag);
+    }
+    game.run_frames(2);
+
+    assert_eq!(game.health(), health);
+    assert_eq!(
+        game.container(bag).products(),
+        vec![ProductKind::new("jerky"), ProductKind::new("jerky")]
+    );
+}
+
+#[test]
+fn valid_stack_scores() {
+    let mut game = HeadlessGame::new(1);
+    let bag = game.bags()[2];
+    let health = game.health();
+
+    for kind in [
+        ProductKind::new("cinder"),
+        ProductKind::new("jerky"),
+        ProductKind::new("plate"),
+    ] {
+        let product = game.spawn_product(kind);
+        game.drag_product_to_bag(product, bag);
+    }
+    game.run_frames(2);
+
+    assert_eq!(game.health(), health);
+    assert!(game.container(bag).products().is_empty());
+    assert_eq!(game.world().resource::<ShiftStats>().bags_cleared, 1);
+    assert_eq!(game.world().resource::<Score>().points, 400);
+}
+
+#[test]
+fn smaller_bag_clears_sooner() {
+    let mut game = HeadlessGame::with_bags(1, &["plastic"]);
+    let bag = game.bags()[0];
+    assert_eq!(game.container(bag).slots.len(), 2);
+
+    for kind in [ProductKind::new("jerky"), ProductKind::new("jerky")] {
+        let product = game.spawn_product(kind);
+        game.drag_product_to_bag(product, bag);
+    }
+    game.run_frames(2);
+
+    assert!(game.container(bag).products().is_empty());
+    assert_eq!(game.world().resource::<ShiftStats>().bags_cleared, 1);
+}
+
+#[test]
+fn unaccepted_product_ruins_bag() {
+    let mut game = HeadlessGame::with_bags(1, &["cooler"]);
+    let bag = game.bags()[0];
+    let health = game.health();
+
+    // stacks fine, but none of it is Cold
+    for kind in [
+        ProductKind::new("cinder"),
+        ProductKind::new("jerky"),
+        ProductKind::new("plate"),
+    ] {
+        let product = game.spawn_product(kind);
+        game.drag_product_to_bag(product, bag);
+    }
+    game.run_frames(2);
+
+    assert_eq!(game.health(), health - 1);
+    assert_eq!(game.world().resource::<ShiftStats>().bags_failed, 1);
+}
+
+#[test]
+fn sent_partial_bag_is_replaced() {
+    let mut game = HeadlessGame::new(1);
+    let bag = game.bags()[0];
+    let health = game.health();
+
+    let mut products = vec![];
+    for kind in [ProductKind::new("jerky"), ProductKind::new("jerky")] {
+        let product = game.spawn_product(kind);
+        game.drag_product_to_bag(product, bag);
+        products.push(product);
+    }
+    game.send_bag(bag);
+    game.run_for(1.);
+
+    assert_eq!(game.health(), health);
+    assert_eq!(game.world().resource::<ShiftStats>().bags_cleared, 1);
+    // no combo multiplier for short bags
+    assert_eq!(game.world().resource::<Score>().points, 275);
+    assert_eq!(game.world().resource::<Score>().combo, 0);
+    assert!(products.iter().all(|product| !game.exists(*product)));
+    assert!(!game.exists(bag));
+    let bags = game.bags();
+    assert_eq!(bags.len(), 3);
+    assert!(game.container(bags[0]).products().is_empty());
+}
+
+#[test]
+fn sent_partial_bag_breaks_combo() {
+    let mut game = HeadlessGame::new(1);
+    let bags = game.bags();
+
+    for kind in [
+        ProductKind::new("cinder"),
+        ProductKind::new("jerky"),
+        ProductKind::new("plate"),
+    ] {
+        let product = game.spawn_product(kind);
+        game.drag_product_to_bag(product, bags[2]);
+    }
+    game.run_frames(2);
+    assert_eq!(game.world().resource::<Score>().combo, 1);
+
+    for kind in [ProductKind::new("jerky"), ProductKind::new("jerky")] {
+        let product = game.spawn_product(kind);
+        game.drag_product_to_bag(product, bags[0]);
+    }
+    game.send_bag(bags[0]);
+    game.run_for(1.);
+
+    let score = game.world().resource::<Score>();
+    assert_eq!(score.points, 400 + 275);
+    assert_eq!(score.combo, 0);
+    assert_eq!(score.best_combo, 1);
+}
+
+#[test]
+fn focused_bag_is_sent() {
+    let mut game = HeadlessGame::new(1);
+    game.press_key(KeyCode::Up);
+    let bag = game.world().resource::<Focus>().entity.unwrap();
+
+    for kind in [ProductKind::new("jerky"), ProductKind::new("jerky")] {
+        let product = game.spawn_product(kind);
+        game.drag_product_to_bag(product, bag);
+    }
+    assert_eq!(game.container(bag).products().len(), 2);
+    game.press_key(KeyCode::Back);
+    game.run_for(1.);
+
+    assert_eq!(game.world().resource::<ShiftStats>().bags_cleared, 1);
+    assert!(!game.exists(bag));
+}
+
+#[test]
+fn runoff_product_damages_player() {
+    let mut game = HeadlessGame::new(1);
+    let health = game.health();
+
+    let product = game.spawn_product(ProductKind::new("beans"));
+    game.run_for(10.);
+    assert!(game.exists(product));
+    assert_eq!(game.health(), health);
+
+    game.run_for(10.);
+    assert!(!game.exists(product));
+    assert_eq!(game.health(), health - 1);
+    assert_eq!(game.world().resource::<ShiftStats>().products_missed, 1);
+}
+
+#[test]
+fn served_customer_leaves() {
+    let mut game = HeadlessGame::new(1);
+    let order = vec![
+        ProductKind::new("plate"),
+        ProductKind::new("cinder"),
+        ProductKind::new("jerky"),
+    ];
+    let customer = game.spawn_customer(order);
+    let bag = game.world().get::<CustomerOrder>(customer).unwrap().bag;
+    let health = game.health();
+
+    for kind in [
+        ProductKind::new("cinder"),
+        ProductKind::new("jerky"),
+        ProductKind::new("plate"),
+    ] {
+        let product = game.spawn_product(kind);
+        game.drag_product_to_bag(product, bag);
+    }
+    game.run_frames(2);
+
+    assert_eq!(game.health(), health);
+    assert!(game.world().get::<CustomerOrder>(customer).is_none());
+    assert!(game.world().get::<Customer>(customer).unwrap().leaving());
+}
+
+#[test]
+fn wrong_order_costs_one_health() {
+    let mut game = HeadlessGame::new(1);
+    let customer = game.spawn_customer(vec![
+        ProductKind::new("cinder"),
+        ProductKind::new("jerky"),
+        ProductKind::new("plate"),
+    ]);
+    let bag = game.world().get::<CustomerOrder>(customer).unwrap().bag;
+    let health = game.health();
+
+    for kind in [
+        ProductKind::new("cinder"),
+        ProductKind::new("jerky"),
+        ProductKind::new("jerky"),
+    ] {
+        let product = game.spawn_product(kind);
+        game.drag_product_to_bag(product, bag);
+    }
+    game.run_frames(2);
+
+    assert_eq!(game.health(), health - 1);
+    assert!(game.world().get::<CustomerOrder>(customer).is_none());
+}
+
+#[test]
+fn impatient_customer_costs_one_health() {
+    let mut game = HeadlessGame::new(1);
+    let customer = game.spawn_customer(vec![ProductKind::new("beans")]);
+    let health = game.health();
+
+    game.run_for(10.);
+    assert!(game.world().get::<Customer>(customer).unwrap().waiting());
+    assert_eq!(game.health(), health);
+
+    game.run_for(50.);
+    assert!(game.world().get::<CustomerOrder>(customer).is_none());
+    assert_eq!(game.health(), health - 1);
+}
+
+#[test]
+fn product_is_handed_over_between_belts() {
+    let mut game = HeadlessGame::new(1);
+    let health = game.health();
+    let desk_conveyor = game.desk_conveyor();
+    // 400 long, ending where the desk's belt starts
+    let feeder = game.spawn_conveyor(
+        vec![Vec2::new(-1466., -387.), Vec2::new(-1066., -387.)],
+        Some(desk_conveyor),
+    );
+
+    let product = game.spawn_product_on(ProductKind::new("beans"), feeder);
+    game.run_for(2.);
+    let item = game.world().get::<ConveyorItem>(product).unwrap();
+    assert_eq!(item.conveyor, feeder);
+    assert!(item.progress > 100. && item.progress < 400.);
+
+    game.run_for(3.);
+    let item = game.world().get::<ConveyorItem>(product).unwrap();
+    assert_eq!(item.conveyor, desk_conveyor);
+    assert!(item.progress > 0. && item.progress < 200.);
+    assert_eq!(game.health(), health);
+
+    // the desk's belt is 1666 long
+    game.run_for(14.);
+    assert!(game.exists(product));
+    assert_eq!(game.health(), health);
+
+    game.run_for(4.);
+    assert!(!game.exists(product));
+    assert_eq!(game.health(), health - 1);
+    assert_eq!(game.world().resource::<ShiftStats>().products_missed, 1);
+}
+
+#[test]
+fn closing_mid_shift_counts_the_shift() {
+    let mut game = HeadlessGame::new(1);
+    game.run_for(2.);
+    assert_eq!(game.world().resource::<Records>().shifts_played, 0);
+
+    game.world_mut().send_event(AppExit);
+    game.step();
+    let records = game.world().resource::<Records>();
+    assert_eq!(records.shifts_played, 1);
+    assert!(records.longest_shift >= 2.);
+}
+
+#[test]
+fn recorded_shift_replays() {
+    let path = std::env::temp_dir().join(format!("bagger-replay-{}.ron", std::process::id()));
+    let path = path.to_str().unwrap();
+    let mut game = HeadlessGame::recording(3, path);
+    for i in 0..6 {
+        game.run_for(3.);
+        if let Some(product) = game.products().first().copied() {
+            let bag = game.bags()[i % 3];
+            game.drag_product_to_bag(product, bag);
+        }
+    }
+    game.run_for(3.);
+
+    let replay = headless_replay(3, path);
+    std::fs::remove_file(path).unwrap();
+
+    let (score, replayed_score) = (
+        game.world().resource::<Score>(),
+        replay.world.resource::<Score>(),
+    );
+    assert_eq!(replayed_score.points, score.points);
+    assert_eq!(replayed_score.best_combo, score.best_combo);
+    let (stats, replayed_stats) = (
+        game.world().resource::<ShiftStats>(),
+        replay.world.resource::<ShiftStats>(),
+    );
+    assert_eq!(replayed_stats.time, stats.time);
+    assert_eq!(replayed_stats.bags_cleared, stats.bags_cleared);
+    assert_eq!(replayed_stats.bags_failed, stats.bags_failed);
+    assert_eq!(replayed_stats.products_bagged, stats.products_bagged);
+    assert_eq!(replayed_stats.products_missed, stats.products_missed);
+    assert_eq!(replay.world.resource::<Health>().amount, game.health());
+}